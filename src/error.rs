@@ -1,9 +1,38 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+#[cfg(not(feature = "std"))]
+use core::fmt;
+#[cfg(feature = "std")]
 use thiserror::Error;
 
-#[derive(Error, Debug)]
+#[cfg_attr(feature = "std", derive(Error))]
+#[derive(Debug)]
 pub enum UtilsError {
-    #[error("Invalid argument: {0}")]
+    #[cfg_attr(feature = "std", error("Invalid argument: {0}"))]
     InvalidArgument(String),
+    /// A pattern passed to one of the regex methods doesn't compile.
+    #[cfg(feature = "regex")]
+    #[error("Invalid regex: {0}")]
+    InvalidRegex(#[from] regex::Error),
+    /// The replacements passed to `replace_each_repeatedly` feed each other forever. Holds the
+    /// search strings along the cycle, starting and ending with the same one.
+    #[cfg_attr(feature = "std", error("Replacement cycle detected: {}", .0.join(" -> ")))]
+    ReplacementCycle(Vec<String>),
 }
 
+#[cfg(not(feature = "std"))]
+impl fmt::Display for UtilsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UtilsError::InvalidArgument(message) => write!(f, "Invalid argument: {}", message),
+            UtilsError::ReplacementCycle(cycle) => {
+                write!(f, "Replacement cycle detected: {}", cycle.join(" -> "))
+            }
+        }
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl core::error::Error for UtilsError {}
+
 pub type UtilsResult<T> = Result<T, UtilsError>;
@@ -1,10 +1,21 @@
 //! This crate is used for hold this name before the development is finished.
+//!
+//! The crate is `no_std` and only needs `alloc`, and gives the same results with or without
+//! the default `std` feature. The `regex` feature requires `std`.
 
-// We still use the name string_utils which is used by others.
+#![no_std]
+
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+mod case_tables;
 pub mod error;
-#[crate_name = "string-utils"]
-#[allow(unused)]
-#[allow(dead_code)]
+mod macros;
+mod option_string_utils;
+mod string_joiner;
 mod string_utils;
 
-pub use string_utils::StringUtilsExt;
+pub use option_string_utils::OptionStringUtilsExt;
+pub use string_joiner::StringJoiner;
+pub use string_utils::{PadPolicy, StringUtils, StringUtilsExt};
@@ -0,0 +1,84 @@
+//! Varargs-style wrappers around the [`StringUtils`](crate::StringUtils) helpers.
+//!
+//! Each macro accepts a mix of `&str`, `String` and other `AsRef<str>` values, so call sites
+//! read like the Java originals: `is_any_blank!(name, email, phone)`.
+
+/// Tests whether any of the values is blank.
+///
+/// See [`StringUtils::is_any_blank`](crate::StringUtils::is_any_blank).
+#[macro_export]
+macro_rules! is_any_blank {
+    ($($value:expr),+ $(,)?) => {
+        $crate::StringUtils::is_any_blank([$(::core::convert::AsRef::<str>::as_ref(&$value)),*])
+    };
+}
+
+/// Tests whether any of the values is empty.
+///
+/// See [`StringUtils::is_any_empty`](crate::StringUtils::is_any_empty).
+#[macro_export]
+macro_rules! is_any_empty {
+    ($($value:expr),+ $(,)?) => {
+        $crate::StringUtils::is_any_empty([$(::core::convert::AsRef::<str>::as_ref(&$value)),*])
+    };
+}
+
+/// Tests whether all of the values are blank.
+///
+/// See [`StringUtils::is_all_blank`](crate::StringUtils::is_all_blank).
+#[macro_export]
+macro_rules! is_all_blank {
+    ($($value:expr),+ $(,)?) => {
+        $crate::StringUtils::is_all_blank([$(::core::convert::AsRef::<str>::as_ref(&$value)),*])
+    };
+}
+
+/// Tests whether all of the values are empty.
+///
+/// See [`StringUtils::is_all_empty`](crate::StringUtils::is_all_empty).
+#[macro_export]
+macro_rules! is_all_empty {
+    ($($value:expr),+ $(,)?) => {
+        $crate::StringUtils::is_all_empty([$(::core::convert::AsRef::<str>::as_ref(&$value)),*])
+    };
+}
+
+/// Tests whether none of the values is blank.
+///
+/// See [`StringUtils::is_none_blank`](crate::StringUtils::is_none_blank).
+#[macro_export]
+macro_rules! is_none_blank {
+    ($($value:expr),+ $(,)?) => {
+        $crate::StringUtils::is_none_blank([$(::core::convert::AsRef::<str>::as_ref(&$value)),*])
+    };
+}
+
+/// Tests whether none of the values is empty.
+///
+/// See [`StringUtils::is_none_empty`](crate::StringUtils::is_none_empty).
+#[macro_export]
+macro_rules! is_none_empty {
+    ($($value:expr),+ $(,)?) => {
+        $crate::StringUtils::is_none_empty([$(::core::convert::AsRef::<str>::as_ref(&$value)),*])
+    };
+}
+
+/// Returns the first value that is not blank as a `&str`, or `None`.
+///
+/// See [`StringUtils::first_non_blank`](crate::StringUtils::first_non_blank).
+#[macro_export]
+macro_rules! first_non_blank {
+    ($($value:expr),+ $(,)?) => {
+        $crate::StringUtils::first_non_blank([$(::core::convert::AsRef::<str>::as_ref(&$value)),*])
+    };
+}
+
+/// Returns the first value that is not empty as a `&str`, or `None`.
+///
+/// See [`StringUtils::first_non_empty`](crate::StringUtils::first_non_empty).
+#[macro_export]
+macro_rules! first_non_empty {
+    ($($value:expr),+ $(,)?) => {
+        $crate::StringUtils::first_non_empty([$(::core::convert::AsRef::<str>::as_ref(&$value)),*])
+    };
+}
@@ -0,0 +1,1271 @@
+//! Null-safe [`StringUtilsExt`] for optional strings.
+
+use crate::error::UtilsResult;
+use crate::string_utils::{StringUtilsExt, EMPTY};
+use crate::{PadPolicy, StringUtils};
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::ops::RangeBounds;
+
+/// The methods of [`StringUtilsExt`] for an `Option` of a string, with `None` standing for
+/// Java's `null`, so code using Commons Lang can be ported call for call.
+///
+/// `None` follows the Java rules:
+///
+/// - methods returning a string return `None`, and fallible ones return `Ok(None)` without
+///   checking their arguments;
+/// - [`is_blank`], [`is_empty`], [`contains_none`] and [`contains_none_in`] are `true`, every
+///   other test is `false`;
+/// - searches find nothing, and [`count_matches`] is `0`;
+/// - `None` sorts before any string and only equals `None`.
+///
+/// The trait is implemented for `Option<S>` where `S` is any `AsRef<str>` type, such as
+/// `Option<&str>`, `Option<String>` and `Option<Cow<str>>`.
+///
+/// [`is_blank`]: OptionStringUtilsExt::is_blank
+/// [`is_empty`]: OptionStringUtilsExt::is_empty
+/// [`contains_none`]: OptionStringUtilsExt::contains_none
+/// [`contains_none_in`]: OptionStringUtilsExt::contains_none_in
+/// [`count_matches`]: OptionStringUtilsExt::count_matches
+pub trait OptionStringUtilsExt {
+    fn abbreviate(&self, max_width: usize) -> UtilsResult<Option<Cow<'_, str>>>;
+    fn abbreviate_with_abbrev_marker(
+        &self,
+        abbrev_marker: &str,
+        max_width: usize,
+    ) -> UtilsResult<Option<Cow<'_, str>>>;
+    fn abbreviate_with_full_opt(
+        &self,
+        abbrev_marker: &str,
+        offset: isize,
+        max_width: usize,
+    ) -> UtilsResult<Option<Cow<'_, str>>>;
+    fn abbreviate_middle(&self, middle: &str, length: usize) -> Option<Cow<'_, str>>;
+    fn abbreviate_with_offset(
+        &self,
+        offset: isize,
+        max_width: usize,
+    ) -> UtilsResult<Option<Cow<'_, str>>>;
+    fn abbreviate_to_width(&self, max_width: usize) -> UtilsResult<Option<Cow<'_, str>>>;
+    fn abbreviate_to_width_with_abbrev_marker(
+        &self,
+        abbrev_marker: &str,
+        max_width: usize,
+    ) -> UtilsResult<Option<Cow<'_, str>>>;
+    fn abbreviate_to_width_with_full_opt(
+        &self,
+        abbrev_marker: &str,
+        offset: isize,
+        max_width: usize,
+    ) -> UtilsResult<Option<Cow<'_, str>>>;
+    fn append_if_missing(&self, suffix: &str, ignore_case: bool) -> Option<Cow<'_, str>>;
+    fn append_if_missing_one_of(
+        &self,
+        suffix: &str,
+        suffixes: &[&str],
+        ignore_case: bool,
+    ) -> Option<Cow<'_, str>>;
+    fn capitalize(&self) -> Option<Cow<'_, str>>;
+    fn center(&self, size: usize) -> Option<Cow<'_, str>>;
+    fn center_with(&self, size: usize, pad_str: &str) -> Option<Cow<'_, str>>;
+    fn center_with_char(&self, size: usize, pad_char: char) -> Option<Cow<'_, str>>;
+    fn center_to_width(&self, width: usize) -> Option<Cow<'_, str>>;
+    fn center_to_width_with(
+        &self,
+        width: usize,
+        pad_str: &str,
+        policy: PadPolicy,
+    ) -> Option<Cow<'_, str>>;
+    fn center_to_width_with_char(
+        &self,
+        width: usize,
+        pad_char: char,
+        policy: PadPolicy,
+    ) -> Option<Cow<'_, str>>;
+    fn chomp(&self) -> Option<&str>;
+    fn chomp_specified(&self, separator: &str) -> Option<&str>;
+    fn chop(&self) -> Option<&str>;
+    /// Compares with `str2`, ordering `None` before any string.
+    fn compare(&self, str2: Option<&str>) -> Ordering;
+    /// Compares with `str2` ignoring case, ordering `None` before any string.
+    fn compare_ignore_case(&self, str2: Option<&str>) -> Ordering;
+    fn contains(&self, search: &str) -> bool;
+    fn contains_char(&self, search_char: char) -> bool;
+    fn contains_any(&self, searches: &[&str]) -> bool;
+    fn contains_any_char(&self, searches: &[char]) -> bool;
+    fn contains_any_in(&self, searches_str: &str) -> bool;
+    fn contains_any_ignore_case(&self, searches: &[&str]) -> bool;
+    fn contains_any_with<T>(&self, searches: &[&str], test: T) -> bool
+    where
+        T: Fn(&str, &str) -> bool;
+    fn contains_ignore_case(&self, search: &str) -> bool;
+    fn contains_none(&self, searches: &[char]) -> bool;
+    fn contains_none_in(&self, invalid_chars: &str) -> bool;
+    fn contains_only(&self, valid: &[char]) -> bool;
+    fn contains_only_in(&self, valid_chars: &str) -> bool;
+    fn contains_whitespace(&self) -> bool;
+    fn count_matches(&self, ch: char) -> u64;
+    fn count_matches_str(&self, sub: &str) -> u64;
+    /// Returns `default_str` if the string is `None` or blank.
+    fn default_if_blank<'a>(&'a self, default_str: &'a str) -> &'a str;
+    /// Returns `default_str` if the string is `None` or empty.
+    fn default_if_empty<'a>(&'a self, default_str: &'a str) -> &'a str;
+    /// Returns the string, or an empty string if it is `None`.
+    fn default_string(&self) -> &str;
+    fn delete_whitespace(&self) -> Option<Cow<'_, str>>;
+    fn delete_whitespace_to_none(&self) -> Option<Cow<'_, str>>;
+    /// Like [`StringUtilsExt::difference`]; all of `str2` differs from `None`.
+    fn difference<'a>(&self, str2: &'a str) -> &'a str;
+    fn digits(&self) -> Option<String>;
+    /// Like [`StringUtilsExt::display_width`]; `None` takes up no columns.
+    fn display_width(&self) -> usize;
+    fn end_with(&self, suffix: &str, ignore_case: bool) -> bool;
+    fn end_with_any(&self, searches: &[&str]) -> bool;
+    fn end_with_consider_case(&self, suffix: &str) -> bool;
+    fn end_with_ignore_case(&self, suffix: &str) -> bool;
+    /// Tests whether both strings are equal or both are `None`.
+    fn equals(&self, str2: Option<&str>) -> bool;
+    fn equals_any(&self, searches: &[&str]) -> bool;
+    fn equals_any_ignore_case(&self, searches: &[&str]) -> bool;
+    /// Tests whether both strings are equal ignoring case or both are `None`.
+    fn equals_ignore_case(&self, str2: Option<&str>) -> bool;
+    /// Returns the result of `default_supplier` if the string is `None` or blank.
+    fn if_blank<T>(&self, default_supplier: T) -> Cow<'_, str>
+    where
+        T: FnOnce() -> String;
+    /// Returns the result of `default_supplier` if the string is `None` or empty.
+    fn if_empty<T>(&self, default_supplier: T) -> Cow<'_, str>
+    where
+        T: FnOnce() -> String;
+    fn index_of(&self, search: &str) -> Option<usize>;
+    fn index_of_any(&self, searches: &[&str]) -> Option<usize>;
+    fn index_of_any_but(&self, search_chars: &[char]) -> Option<usize>;
+    fn index_of_any_but_in(&self, search_chars: &str) -> Option<usize>;
+    fn index_of_any_char(&self, search_chars: &[char]) -> Option<usize>;
+    fn index_of_any_in(&self, search_chars: &str) -> Option<usize>;
+    fn index_of_char(&self, search_char: char) -> Option<usize>;
+    fn index_of_char_starting_from(&self, search_char: char, start_pos: usize) -> Option<usize>;
+    fn index_of_starting_from(&self, search: &str, start_pos: usize) -> Option<usize>;
+    /// Like [`StringUtilsExt::index_of_difference`]; `None` differs from any string at `0`.
+    fn index_of_difference(&self, str2: &str) -> Option<usize>;
+    fn index_of_ignore_case(&self, search: &str) -> Option<usize>;
+    fn index_of_ignore_case_starting_from(&self, search: &str, start_pos: usize) -> Option<usize>;
+    fn is_alpha(&self) -> bool;
+    fn is_alphanumeric(&self) -> bool;
+    fn is_alphanumeric_space(&self) -> bool;
+    fn is_alpha_space(&self) -> bool;
+    fn is_ascii_alpha(&self) -> bool;
+    fn is_ascii_alphanumeric(&self) -> bool;
+    fn is_ascii_alphanumeric_space(&self) -> bool;
+    fn is_ascii_alpha_space(&self) -> bool;
+    fn is_ascii_numeric(&self) -> bool;
+    fn is_ascii_numeric_space(&self) -> bool;
+    fn is_ascii_printable(&self) -> bool;
+    fn is_blank(&self) -> bool;
+    fn is_empty(&self) -> bool;
+    fn is_mixed_case(&self) -> bool;
+    fn is_not_blank(&self) -> bool;
+    fn is_not_empty(&self) -> bool;
+    fn is_numeric(&self) -> bool;
+    fn is_numeric_space(&self) -> bool;
+    fn is_whitespace(&self) -> bool;
+    fn last_index_of(&self, search: &str) -> Option<usize>;
+    fn last_index_of_any(&self, searches: &[&str]) -> Option<usize>;
+    fn last_index_of_char(&self, search_char: char) -> Option<usize>;
+    fn last_index_of_char_starting_from(
+        &self,
+        search_char: char,
+        start_pos: usize,
+    ) -> Option<usize>;
+    fn last_index_of_ignore_case(&self, search: &str) -> Option<usize>;
+    fn last_index_of_ignore_case_starting_from(
+        &self,
+        search: &str,
+        start_pos: usize,
+    ) -> Option<usize>;
+    fn last_index_of_starting_from(&self, search: &str, start_pos: usize) -> Option<usize>;
+    fn last_ordinal_index_of(&self, search: &str, ordinal: usize) -> Option<usize>;
+    fn left(&self, len: usize) -> Option<&str>;
+    fn left_pad(&self, size: usize) -> Option<Cow<'_, str>>;
+    fn left_pad_with(&self, size: usize, pad_char: char) -> Option<Cow<'_, str>>;
+    fn left_pad_with_str(&self, size: usize, pad_str: &str) -> Option<Cow<'_, str>>;
+    fn left_pad_to_width(&self, width: usize) -> Option<Cow<'_, str>>;
+    fn left_pad_to_width_with(
+        &self,
+        width: usize,
+        pad_char: char,
+        policy: PadPolicy,
+    ) -> Option<Cow<'_, str>>;
+    fn left_pad_to_width_with_str(
+        &self,
+        width: usize,
+        pad_str: &str,
+        policy: PadPolicy,
+    ) -> Option<Cow<'_, str>>;
+    fn lower_case(&self) -> Option<String>;
+    fn mid(&self, pos: usize, len: usize) -> Option<&str>;
+    fn normalize_space(&self) -> Option<Cow<'_, str>>;
+    fn normalize_space_to_none(&self) -> Option<Cow<'_, str>>;
+    fn ordinal_index_of(&self, search: &str, ordinal: usize) -> Option<usize>;
+    fn overlay(&self, overlay: &str, start: usize, end: usize) -> Option<String>;
+    fn prepend_if_missing(&self, prefix: &str, ignore_case: bool) -> Option<Cow<'_, str>>;
+    fn prepend_if_missing_one_of(
+        &self,
+        prefix: &str,
+        prefixes: &[&str],
+        ignore_case: bool,
+    ) -> Option<Cow<'_, str>>;
+    fn remove(&self, remove: &str) -> Option<Cow<'_, str>>;
+    fn remove_char(&self, remove: char) -> Option<Cow<'_, str>>;
+    fn remove_all(&self, remove: &str) -> Option<Cow<'_, str>>;
+    #[cfg(feature = "regex")]
+    fn remove_all_regex(&self, regex: &str) -> UtilsResult<Option<Cow<'_, str>>>;
+    fn remove_end(&self, remove: &str) -> Option<&str>;
+    fn remove_end_ignore_case(&self, remove: &str) -> Option<&str>;
+    fn remove_first(&self, remove: &str) -> Option<Cow<'_, str>>;
+    #[cfg(feature = "regex")]
+    fn remove_first_regex(&self, regex: &str) -> UtilsResult<Option<Cow<'_, str>>>;
+    fn remove_ignore_case(&self, remove: &str) -> Option<Cow<'_, str>>;
+    #[cfg(feature = "regex")]
+    fn remove_pattern(&self, regex: &str) -> UtilsResult<Option<Cow<'_, str>>>;
+    fn remove_start(&self, remove: &str) -> Option<&str>;
+    fn remove_start_char(&self, remove: char) -> Option<&str>;
+    fn remove_start_ignore_case(&self, remove: &str) -> Option<&str>;
+    fn repeat(&self, repeat: usize) -> Option<String>;
+    #[cfg(feature = "regex")]
+    fn replace_all_regex(
+        &self,
+        regex: &str,
+        replacement: &str,
+    ) -> UtilsResult<Option<Cow<'_, str>>>;
+    fn replace_chars(&self, search: char, replace: char) -> Option<Cow<'_, str>>;
+    fn replace_chars_in(&self, search_chars: &str, replace_chars: &str) -> Option<Cow<'_, str>>;
+    fn replace_each(
+        &self,
+        search_list: &[&str],
+        replace_list: &[&str],
+    ) -> UtilsResult<Option<Cow<'_, str>>>;
+    fn replace_each_repeatedly(
+        &self,
+        search_list: &[&str],
+        replace_list: &[&str],
+        time_to_live: Option<usize>,
+    ) -> UtilsResult<Option<Cow<'_, str>>>;
+    fn replace_first(&self, search: &str, replace: &str) -> Option<Cow<'_, str>>;
+    #[cfg(feature = "regex")]
+    fn replace_first_regex(
+        &self,
+        regex: &str,
+        replacement: &str,
+    ) -> UtilsResult<Option<Cow<'_, str>>>;
+    #[cfg(feature = "regex")]
+    fn replace_pattern(&self, regex: &str, replacement: &str) -> UtilsResult<Option<Cow<'_, str>>>;
+    fn reverse(&self) -> Option<String>;
+    fn reverse_code_points(&self) -> Option<String>;
+    fn reverse_delimited(&self, separator_char: char) -> Option<String>;
+    fn reverse_delimited_code_points(&self, separator_char: char) -> Option<String>;
+    fn right(&self, len: usize) -> Option<&str>;
+    fn right_pad(&self, size: usize) -> Option<Cow<'_, str>>;
+    fn right_pad_with(&self, size: usize, pad_char: char) -> Option<Cow<'_, str>>;
+    fn right_pad_with_str(&self, size: usize, pad_str: &str) -> Option<Cow<'_, str>>;
+    fn right_pad_to_width(&self, width: usize) -> Option<Cow<'_, str>>;
+    fn right_pad_to_width_with(
+        &self,
+        width: usize,
+        pad_char: char,
+        policy: PadPolicy,
+    ) -> Option<Cow<'_, str>>;
+    fn right_pad_to_width_with_str(
+        &self,
+        width: usize,
+        pad_str: &str,
+        policy: PadPolicy,
+    ) -> Option<Cow<'_, str>>;
+    fn rotate(&self, shift: isize) -> Option<String>;
+    fn rotate_code_points(&self, shift: isize) -> Option<String>;
+    fn split_by_chars(&self, separator_chars: Option<&str>) -> Option<Vec<&str>>;
+    fn split_by_chars_max(&self, separator_chars: Option<&str>, max: usize) -> Option<Vec<&str>>;
+    fn split_by_character_type(&self) -> Option<Vec<&str>>;
+    fn split_by_character_type_and_camel_case(&self) -> Option<Vec<&str>>;
+    fn split_by_whole_separator(&self, separator: Option<&str>) -> Option<Vec<&str>>;
+    fn split_by_whole_separator_max(
+        &self,
+        separator: Option<&str>,
+        max: usize,
+    ) -> Option<Vec<&str>>;
+    fn split_preserve_all_tokens(&self, separator_chars: Option<&str>) -> Option<Vec<&str>>;
+    fn split_preserve_all_tokens_max(
+        &self,
+        separator_chars: Option<&str>,
+        max: usize,
+    ) -> Option<Vec<&str>>;
+    fn starts_with(&self, prefix: &str, ignore_case: bool) -> bool;
+    fn starts_with_any(&self, prefixes: &[&str]) -> bool;
+    fn strip(&self) -> Option<&str>;
+    fn strip_in(&self, strip_chars: &str) -> Option<&str>;
+    fn strip_end(&self) -> Option<&str>;
+    fn strip_end_in(&self, strip_chars: &str) -> Option<&str>;
+    fn strip_start(&self) -> Option<&str>;
+    fn strip_start_in(&self, strip_chars: &str) -> Option<&str>;
+    /// Strips whitespace from both ends, returning an empty string for `None`.
+    fn strip_to_empty(&self) -> &str;
+    /// Strips whitespace from both ends, returning `None` if what's left is blank.
+    fn strip_to_none(&self) -> Option<&str>;
+    fn substring<R>(&self, range: R) -> Option<&str>
+    where
+        R: RangeBounds<usize>;
+    fn substring_after(&self, separator: char) -> Option<&str>;
+    fn substring_after_last(&self, separator: char) -> Option<&str>;
+    fn substring_after_last_str(&self, separator: &str) -> Option<&str>;
+    fn substring_after_str(&self, separator: &str) -> Option<&str>;
+    fn substring_before(&self, separator: char) -> Option<&str>;
+    fn substring_before_last(&self, separator: char) -> Option<&str>;
+    fn substring_before_last_str(&self, separator: &str) -> Option<&str>;
+    fn substring_before_str(&self, separator: &str) -> Option<&str>;
+    fn substring_between(&self, open: &str, close: &str) -> Option<&str>;
+    fn substring_between_tag(&self, tag: &str) -> Option<&str>;
+    fn substring_between_to_none(&self, open: &str, close: &str) -> Option<&str>;
+    fn swap_case(&self) -> Option<String>;
+    fn trim(&self) -> Option<&str>;
+    /// Trims control chars and spaces from both ends, returning an empty string for `None`.
+    fn trim_to_empty(&self) -> &str;
+    /// Trims control chars and spaces from both ends, returning `None` if what's left is blank.
+    fn trim_to_none(&self) -> Option<&str>;
+    fn truncate(&self, max_width: usize) -> Option<&str>;
+    fn truncate_with_offset(&self, offset: usize, max_width: usize) -> Option<&str>;
+    fn truncate_to_width(&self, max_width: usize) -> Option<&str>;
+    fn truncate_to_width_with_offset(&self, offset: usize, max_width: usize) -> Option<&str>;
+    fn uncapitalize(&self) -> Option<Cow<'_, str>>;
+    fn unwrap_from_char(&self, wrap_char: char) -> Option<&str>;
+    fn unwrap_from_str(&self, wrap_str: &str) -> Option<&str>;
+    fn unwrap_quoted(&self, quote: char) -> Option<Cow<'_, str>>;
+    fn upper_case(&self) -> Option<String>;
+    fn wrap(&self, wrap_with: char) -> Option<Cow<'_, str>>;
+    fn wrap_if_missing(&self, wrap_with: char) -> Option<Cow<'_, str>>;
+    fn wrap_if_missing_str(&self, wrap_with: &str) -> Option<Cow<'_, str>>;
+    fn wrap_with_str(&self, wrap_with: &str) -> Option<Cow<'_, str>>;
+}
+
+fn as_str<S>(value: &Option<S>) -> Option<&str>
+where
+    S: AsRef<str>,
+{
+    value.as_ref().map(AsRef::as_ref)
+}
+
+impl<S> OptionStringUtilsExt for Option<S>
+where
+    S: AsRef<str>,
+{
+    fn abbreviate(&self, max_width: usize) -> UtilsResult<Option<Cow<'_, str>>> {
+        as_str(self).map(|s| s.abbreviate(max_width)).transpose()
+    }
+
+    fn abbreviate_with_abbrev_marker(
+        &self,
+        abbrev_marker: &str,
+        max_width: usize,
+    ) -> UtilsResult<Option<Cow<'_, str>>> {
+        as_str(self)
+            .map(|s| s.abbreviate_with_abbrev_marker(abbrev_marker, max_width))
+            .transpose()
+    }
+
+    fn abbreviate_with_full_opt(
+        &self,
+        abbrev_marker: &str,
+        offset: isize,
+        max_width: usize,
+    ) -> UtilsResult<Option<Cow<'_, str>>> {
+        as_str(self)
+            .map(|s| s.abbreviate_with_full_opt(abbrev_marker, offset, max_width))
+            .transpose()
+    }
+
+    fn abbreviate_middle(&self, middle: &str, length: usize) -> Option<Cow<'_, str>> {
+        as_str(self).map(|s| s.abbreviate_middle(middle, length))
+    }
+
+    fn abbreviate_with_offset(
+        &self,
+        offset: isize,
+        max_width: usize,
+    ) -> UtilsResult<Option<Cow<'_, str>>> {
+        as_str(self)
+            .map(|s| s.abbreviate_with_offset(offset, max_width))
+            .transpose()
+    }
+
+    fn abbreviate_to_width(&self, max_width: usize) -> UtilsResult<Option<Cow<'_, str>>> {
+        as_str(self)
+            .map(|s| s.abbreviate_to_width(max_width))
+            .transpose()
+    }
+
+    fn abbreviate_to_width_with_abbrev_marker(
+        &self,
+        abbrev_marker: &str,
+        max_width: usize,
+    ) -> UtilsResult<Option<Cow<'_, str>>> {
+        as_str(self)
+            .map(|s| s.abbreviate_to_width_with_abbrev_marker(abbrev_marker, max_width))
+            .transpose()
+    }
+
+    fn abbreviate_to_width_with_full_opt(
+        &self,
+        abbrev_marker: &str,
+        offset: isize,
+        max_width: usize,
+    ) -> UtilsResult<Option<Cow<'_, str>>> {
+        as_str(self)
+            .map(|s| s.abbreviate_to_width_with_full_opt(abbrev_marker, offset, max_width))
+            .transpose()
+    }
+
+    fn append_if_missing(&self, suffix: &str, ignore_case: bool) -> Option<Cow<'_, str>> {
+        as_str(self).map(|s| s.append_if_missing(suffix, ignore_case))
+    }
+
+    fn append_if_missing_one_of(
+        &self,
+        suffix: &str,
+        suffixes: &[&str],
+        ignore_case: bool,
+    ) -> Option<Cow<'_, str>> {
+        as_str(self).map(|s| s.append_if_missing_one_of(suffix, suffixes, ignore_case))
+    }
+
+    fn capitalize(&self) -> Option<Cow<'_, str>> {
+        as_str(self).map(|s| s.capitalize())
+    }
+
+    fn center(&self, size: usize) -> Option<Cow<'_, str>> {
+        as_str(self).map(|s| s.center(size))
+    }
+
+    fn center_with(&self, size: usize, pad_str: &str) -> Option<Cow<'_, str>> {
+        as_str(self).map(|s| s.center_with(size, pad_str))
+    }
+
+    fn center_with_char(&self, size: usize, pad_char: char) -> Option<Cow<'_, str>> {
+        as_str(self).map(|s| s.center_with_char(size, pad_char))
+    }
+
+    fn center_to_width(&self, width: usize) -> Option<Cow<'_, str>> {
+        as_str(self).map(|s| s.center_to_width(width))
+    }
+
+    fn center_to_width_with(
+        &self,
+        width: usize,
+        pad_str: &str,
+        policy: PadPolicy,
+    ) -> Option<Cow<'_, str>> {
+        as_str(self).map(|s| s.center_to_width_with(width, pad_str, policy))
+    }
+
+    fn center_to_width_with_char(
+        &self,
+        width: usize,
+        pad_char: char,
+        policy: PadPolicy,
+    ) -> Option<Cow<'_, str>> {
+        as_str(self).map(|s| s.center_to_width_with_char(width, pad_char, policy))
+    }
+
+    fn chomp(&self) -> Option<&str> {
+        as_str(self).map(|s| s.chomp())
+    }
+
+    fn chomp_specified(&self, separator: &str) -> Option<&str> {
+        as_str(self).map(|s| s.chomp_specified(separator))
+    }
+
+    fn chop(&self) -> Option<&str> {
+        as_str(self).map(|s| s.chop())
+    }
+
+    fn compare(&self, str2: Option<&str>) -> Ordering {
+        StringUtils::compare_nullable(as_str(self), str2, true)
+    }
+
+    fn compare_ignore_case(&self, str2: Option<&str>) -> Ordering {
+        StringUtils::compare_ignore_case_nullable(as_str(self), str2, true)
+    }
+
+    fn contains(&self, search: &str) -> bool {
+        as_str(self).is_some_and(|s| StringUtilsExt::contains(s, search))
+    }
+
+    fn contains_char(&self, search_char: char) -> bool {
+        as_str(self).is_some_and(|s| s.contains_char(search_char))
+    }
+
+    fn contains_any(&self, searches: &[&str]) -> bool {
+        as_str(self).is_some_and(|s| s.contains_any(searches))
+    }
+
+    fn contains_any_char(&self, searches: &[char]) -> bool {
+        as_str(self).is_some_and(|s| s.contains_any_char(searches))
+    }
+
+    fn contains_any_in(&self, searches_str: &str) -> bool {
+        as_str(self).is_some_and(|s| s.contains_any_in(searches_str))
+    }
+
+    fn contains_any_ignore_case(&self, searches: &[&str]) -> bool {
+        as_str(self).is_some_and(|s| s.contains_any_ignore_case(searches))
+    }
+
+    fn contains_any_with<T>(&self, searches: &[&str], test: T) -> bool
+    where
+        T: Fn(&str, &str) -> bool,
+    {
+        as_str(self).is_some_and(|s| s.contains_any_with(searches, test))
+    }
+
+    fn contains_ignore_case(&self, search: &str) -> bool {
+        as_str(self).is_some_and(|s| s.contains_ignore_case(search))
+    }
+
+    fn contains_none(&self, searches: &[char]) -> bool {
+        as_str(self).map_or(true, |s| s.contains_none(searches))
+    }
+
+    fn contains_none_in(&self, invalid_chars: &str) -> bool {
+        as_str(self).map_or(true, |s| s.contains_none_in(invalid_chars))
+    }
+
+    fn contains_only(&self, valid: &[char]) -> bool {
+        as_str(self).is_some_and(|s| s.contains_only(valid))
+    }
+
+    fn contains_only_in(&self, valid_chars: &str) -> bool {
+        as_str(self).is_some_and(|s| s.contains_only_in(valid_chars))
+    }
+
+    fn contains_whitespace(&self) -> bool {
+        as_str(self).is_some_and(|s| s.contains_whitespace())
+    }
+
+    fn count_matches(&self, ch: char) -> u64 {
+        as_str(self).map_or(0, |s| s.count_matches(ch))
+    }
+
+    fn count_matches_str(&self, sub: &str) -> u64 {
+        as_str(self).map_or(0, |s| s.count_matches_str(sub))
+    }
+
+    fn default_if_blank<'a>(&'a self, default_str: &'a str) -> &'a str {
+        as_str(self).map_or(default_str, |s| s.default_if_blank(default_str))
+    }
+
+    fn default_if_empty<'a>(&'a self, default_str: &'a str) -> &'a str {
+        as_str(self).map_or(default_str, |s| s.default_if_empty(default_str))
+    }
+
+    fn default_string(&self) -> &str {
+        as_str(self).unwrap_or(EMPTY)
+    }
+
+    fn delete_whitespace(&self) -> Option<Cow<'_, str>> {
+        as_str(self).map(|s| s.delete_whitespace())
+    }
+
+    fn delete_whitespace_to_none(&self) -> Option<Cow<'_, str>> {
+        as_str(self).and_then(|s| s.delete_whitespace_to_none())
+    }
+
+    fn difference<'a>(&self, str2: &'a str) -> &'a str {
+        as_str(self).map_or(str2, |s| s.difference(str2))
+    }
+
+    fn digits(&self) -> Option<String> {
+        as_str(self).map(|s| s.digits())
+    }
+
+    fn display_width(&self) -> usize {
+        as_str(self).map_or(0, |s| s.display_width())
+    }
+
+    fn end_with(&self, suffix: &str, ignore_case: bool) -> bool {
+        as_str(self).is_some_and(|s| s.end_with(suffix, ignore_case))
+    }
+
+    fn end_with_any(&self, searches: &[&str]) -> bool {
+        as_str(self).is_some_and(|s| s.end_with_any(searches))
+    }
+
+    fn end_with_consider_case(&self, suffix: &str) -> bool {
+        as_str(self).is_some_and(|s| s.end_with_consider_case(suffix))
+    }
+
+    fn end_with_ignore_case(&self, suffix: &str) -> bool {
+        as_str(self).is_some_and(|s| s.end_with_ignore_case(suffix))
+    }
+
+    fn equals(&self, str2: Option<&str>) -> bool {
+        as_str(self) == str2
+    }
+
+    fn equals_any(&self, searches: &[&str]) -> bool {
+        as_str(self).is_some_and(|s| s.equals_any(searches))
+    }
+
+    fn equals_any_ignore_case(&self, searches: &[&str]) -> bool {
+        as_str(self).is_some_and(|s| s.equals_any_ignore_case(searches))
+    }
+
+    fn equals_ignore_case(&self, str2: Option<&str>) -> bool {
+        match (as_str(self), str2) {
+            (Some(s), Some(str2)) => s.equals_ignore_case(str2),
+            (s, str2) => s.is_none() && str2.is_none(),
+        }
+    }
+
+    fn if_blank<T>(&self, default_supplier: T) -> Cow<'_, str>
+    where
+        T: FnOnce() -> String,
+    {
+        match as_str(self) {
+            Some(s) => s.if_blank(default_supplier),
+            None => Cow::Owned(default_supplier()),
+        }
+    }
+
+    fn if_empty<T>(&self, default_supplier: T) -> Cow<'_, str>
+    where
+        T: FnOnce() -> String,
+    {
+        match as_str(self) {
+            Some(s) => s.if_empty(default_supplier),
+            None => Cow::Owned(default_supplier()),
+        }
+    }
+
+    fn index_of(&self, search: &str) -> Option<usize> {
+        as_str(self).and_then(|s| s.index_of(search))
+    }
+
+    fn index_of_any(&self, searches: &[&str]) -> Option<usize> {
+        as_str(self).and_then(|s| s.index_of_any(searches))
+    }
+
+    fn index_of_any_but(&self, search_chars: &[char]) -> Option<usize> {
+        as_str(self).and_then(|s| s.index_of_any_but(search_chars))
+    }
+
+    fn index_of_any_but_in(&self, search_chars: &str) -> Option<usize> {
+        as_str(self).and_then(|s| s.index_of_any_but_in(search_chars))
+    }
+
+    fn index_of_any_char(&self, search_chars: &[char]) -> Option<usize> {
+        as_str(self).and_then(|s| s.index_of_any_char(search_chars))
+    }
+
+    fn index_of_any_in(&self, search_chars: &str) -> Option<usize> {
+        as_str(self).and_then(|s| s.index_of_any_in(search_chars))
+    }
+
+    fn index_of_char(&self, search_char: char) -> Option<usize> {
+        as_str(self).and_then(|s| s.index_of_char(search_char))
+    }
+
+    fn index_of_char_starting_from(&self, search_char: char, start_pos: usize) -> Option<usize> {
+        as_str(self).and_then(|s| s.index_of_char_starting_from(search_char, start_pos))
+    }
+
+    fn index_of_starting_from(&self, search: &str, start_pos: usize) -> Option<usize> {
+        as_str(self).and_then(|s| s.index_of_starting_from(search, start_pos))
+    }
+
+    fn index_of_difference(&self, str2: &str) -> Option<usize> {
+        as_str(self).map_or(Some(0), |s| s.index_of_difference(str2))
+    }
+
+    fn index_of_ignore_case(&self, search: &str) -> Option<usize> {
+        as_str(self).and_then(|s| s.index_of_ignore_case(search))
+    }
+
+    fn index_of_ignore_case_starting_from(&self, search: &str, start_pos: usize) -> Option<usize> {
+        as_str(self).and_then(|s| s.index_of_ignore_case_starting_from(search, start_pos))
+    }
+
+    fn is_alpha(&self) -> bool {
+        as_str(self).is_some_and(|s| s.is_alpha())
+    }
+
+    fn is_alphanumeric(&self) -> bool {
+        as_str(self).is_some_and(|s| s.is_alphanumeric())
+    }
+
+    fn is_alphanumeric_space(&self) -> bool {
+        as_str(self).is_some_and(|s| s.is_alphanumeric_space())
+    }
+
+    fn is_alpha_space(&self) -> bool {
+        as_str(self).is_some_and(|s| s.is_alpha_space())
+    }
+
+    fn is_ascii_alpha(&self) -> bool {
+        as_str(self).is_some_and(|s| s.is_ascii_alpha())
+    }
+
+    fn is_ascii_alphanumeric(&self) -> bool {
+        as_str(self).is_some_and(|s| s.is_ascii_alphanumeric())
+    }
+
+    fn is_ascii_alphanumeric_space(&self) -> bool {
+        as_str(self).is_some_and(|s| s.is_ascii_alphanumeric_space())
+    }
+
+    fn is_ascii_alpha_space(&self) -> bool {
+        as_str(self).is_some_and(|s| s.is_ascii_alpha_space())
+    }
+
+    fn is_ascii_numeric(&self) -> bool {
+        as_str(self).is_some_and(|s| s.is_ascii_numeric())
+    }
+
+    fn is_ascii_numeric_space(&self) -> bool {
+        as_str(self).is_some_and(|s| s.is_ascii_numeric_space())
+    }
+
+    fn is_ascii_printable(&self) -> bool {
+        as_str(self).is_some_and(|s| s.is_ascii_printable())
+    }
+
+    fn is_blank(&self) -> bool {
+        as_str(self).map_or(true, |s| s.is_blank())
+    }
+
+    fn is_empty(&self) -> bool {
+        as_str(self).map_or(true, StringUtilsExt::is_empty)
+    }
+
+    fn is_mixed_case(&self) -> bool {
+        as_str(self).is_some_and(|s| s.is_mixed_case())
+    }
+
+    fn is_not_blank(&self) -> bool {
+        as_str(self).is_some_and(|s| s.is_not_blank())
+    }
+
+    fn is_not_empty(&self) -> bool {
+        as_str(self).is_some_and(|s| s.is_not_empty())
+    }
+
+    fn is_numeric(&self) -> bool {
+        as_str(self).is_some_and(|s| s.is_numeric())
+    }
+
+    fn is_numeric_space(&self) -> bool {
+        as_str(self).is_some_and(|s| s.is_numeric_space())
+    }
+
+    fn is_whitespace(&self) -> bool {
+        as_str(self).is_some_and(|s| s.is_whitespace())
+    }
+
+    fn last_index_of(&self, search: &str) -> Option<usize> {
+        as_str(self).and_then(|s| s.last_index_of(search))
+    }
+
+    fn last_index_of_any(&self, searches: &[&str]) -> Option<usize> {
+        as_str(self).and_then(|s| s.last_index_of_any(searches))
+    }
+
+    fn last_index_of_char(&self, search_char: char) -> Option<usize> {
+        as_str(self).and_then(|s| s.last_index_of_char(search_char))
+    }
+
+    fn last_index_of_char_starting_from(
+        &self,
+        search_char: char,
+        start_pos: usize,
+    ) -> Option<usize> {
+        as_str(self).and_then(|s| s.last_index_of_char_starting_from(search_char, start_pos))
+    }
+
+    fn last_index_of_ignore_case(&self, search: &str) -> Option<usize> {
+        as_str(self).and_then(|s| s.last_index_of_ignore_case(search))
+    }
+
+    fn last_index_of_ignore_case_starting_from(
+        &self,
+        search: &str,
+        start_pos: usize,
+    ) -> Option<usize> {
+        as_str(self).and_then(|s| s.last_index_of_ignore_case_starting_from(search, start_pos))
+    }
+
+    fn last_index_of_starting_from(&self, search: &str, start_pos: usize) -> Option<usize> {
+        as_str(self).and_then(|s| s.last_index_of_starting_from(search, start_pos))
+    }
+
+    fn last_ordinal_index_of(&self, search: &str, ordinal: usize) -> Option<usize> {
+        as_str(self).and_then(|s| s.last_ordinal_index_of(search, ordinal))
+    }
+
+    fn left(&self, len: usize) -> Option<&str> {
+        as_str(self).map(|s| s.left(len))
+    }
+
+    fn left_pad(&self, size: usize) -> Option<Cow<'_, str>> {
+        as_str(self).map(|s| s.left_pad(size))
+    }
+
+    fn left_pad_with(&self, size: usize, pad_char: char) -> Option<Cow<'_, str>> {
+        as_str(self).map(|s| s.left_pad_with(size, pad_char))
+    }
+
+    fn left_pad_with_str(&self, size: usize, pad_str: &str) -> Option<Cow<'_, str>> {
+        as_str(self).map(|s| s.left_pad_with_str(size, pad_str))
+    }
+
+    fn left_pad_to_width(&self, width: usize) -> Option<Cow<'_, str>> {
+        as_str(self).map(|s| s.left_pad_to_width(width))
+    }
+
+    fn left_pad_to_width_with(
+        &self,
+        width: usize,
+        pad_char: char,
+        policy: PadPolicy,
+    ) -> Option<Cow<'_, str>> {
+        as_str(self).map(|s| s.left_pad_to_width_with(width, pad_char, policy))
+    }
+
+    fn left_pad_to_width_with_str(
+        &self,
+        width: usize,
+        pad_str: &str,
+        policy: PadPolicy,
+    ) -> Option<Cow<'_, str>> {
+        as_str(self).map(|s| s.left_pad_to_width_with_str(width, pad_str, policy))
+    }
+
+    fn lower_case(&self) -> Option<String> {
+        as_str(self).map(|s| s.lower_case())
+    }
+
+    fn mid(&self, pos: usize, len: usize) -> Option<&str> {
+        as_str(self).map(|s| s.mid(pos, len))
+    }
+
+    fn normalize_space(&self) -> Option<Cow<'_, str>> {
+        as_str(self).map(|s| s.normalize_space())
+    }
+
+    fn normalize_space_to_none(&self) -> Option<Cow<'_, str>> {
+        as_str(self).and_then(|s| s.normalize_space_to_none())
+    }
+
+    fn ordinal_index_of(&self, search: &str, ordinal: usize) -> Option<usize> {
+        as_str(self).and_then(|s| s.ordinal_index_of(search, ordinal))
+    }
+
+    fn overlay(&self, overlay: &str, start: usize, end: usize) -> Option<String> {
+        as_str(self).map(|s| s.overlay(overlay, start, end))
+    }
+
+    fn prepend_if_missing(&self, prefix: &str, ignore_case: bool) -> Option<Cow<'_, str>> {
+        as_str(self).map(|s| s.prepend_if_missing(prefix, ignore_case))
+    }
+
+    fn prepend_if_missing_one_of(
+        &self,
+        prefix: &str,
+        prefixes: &[&str],
+        ignore_case: bool,
+    ) -> Option<Cow<'_, str>> {
+        as_str(self).map(|s| s.prepend_if_missing_one_of(prefix, prefixes, ignore_case))
+    }
+
+    fn remove(&self, remove: &str) -> Option<Cow<'_, str>> {
+        as_str(self).map(|s| s.remove(remove))
+    }
+
+    fn remove_char(&self, remove: char) -> Option<Cow<'_, str>> {
+        as_str(self).map(|s| s.remove_char(remove))
+    }
+
+    fn remove_all(&self, remove: &str) -> Option<Cow<'_, str>> {
+        as_str(self).map(|s| s.remove_all(remove))
+    }
+
+    #[cfg(feature = "regex")]
+    fn remove_all_regex(&self, regex: &str) -> UtilsResult<Option<Cow<'_, str>>> {
+        as_str(self).map(|s| s.remove_all_regex(regex)).transpose()
+    }
+
+    fn remove_end(&self, remove: &str) -> Option<&str> {
+        as_str(self).map(|s| s.remove_end(remove))
+    }
+
+    fn remove_end_ignore_case(&self, remove: &str) -> Option<&str> {
+        as_str(self).map(|s| s.remove_end_ignore_case(remove))
+    }
+
+    fn remove_first(&self, remove: &str) -> Option<Cow<'_, str>> {
+        as_str(self).map(|s| s.remove_first(remove))
+    }
+
+    #[cfg(feature = "regex")]
+    fn remove_first_regex(&self, regex: &str) -> UtilsResult<Option<Cow<'_, str>>> {
+        as_str(self)
+            .map(|s| s.remove_first_regex(regex))
+            .transpose()
+    }
+
+    fn remove_ignore_case(&self, remove: &str) -> Option<Cow<'_, str>> {
+        as_str(self).map(|s| s.remove_ignore_case(remove))
+    }
+
+    #[cfg(feature = "regex")]
+    fn remove_pattern(&self, regex: &str) -> UtilsResult<Option<Cow<'_, str>>> {
+        as_str(self).map(|s| s.remove_pattern(regex)).transpose()
+    }
+
+    fn remove_start(&self, remove: &str) -> Option<&str> {
+        as_str(self).map(|s| s.remove_start(remove))
+    }
+
+    fn remove_start_char(&self, remove: char) -> Option<&str> {
+        as_str(self).map(|s| s.remove_start_char(remove))
+    }
+
+    fn remove_start_ignore_case(&self, remove: &str) -> Option<&str> {
+        as_str(self).map(|s| s.remove_start_ignore_case(remove))
+    }
+
+    fn repeat(&self, repeat: usize) -> Option<String> {
+        as_str(self).map(|s| StringUtilsExt::repeat(s, repeat))
+    }
+
+    #[cfg(feature = "regex")]
+    fn replace_all_regex(
+        &self,
+        regex: &str,
+        replacement: &str,
+    ) -> UtilsResult<Option<Cow<'_, str>>> {
+        as_str(self)
+            .map(|s| s.replace_all_regex(regex, replacement))
+            .transpose()
+    }
+
+    fn replace_chars(&self, search: char, replace: char) -> Option<Cow<'_, str>> {
+        as_str(self).map(|s| s.replace_chars(search, replace))
+    }
+
+    fn replace_chars_in(&self, search_chars: &str, replace_chars: &str) -> Option<Cow<'_, str>> {
+        as_str(self).map(|s| s.replace_chars_in(search_chars, replace_chars))
+    }
+
+    fn replace_each(
+        &self,
+        search_list: &[&str],
+        replace_list: &[&str],
+    ) -> UtilsResult<Option<Cow<'_, str>>> {
+        as_str(self)
+            .map(|s| s.replace_each(search_list, replace_list))
+            .transpose()
+    }
+
+    fn replace_each_repeatedly(
+        &self,
+        search_list: &[&str],
+        replace_list: &[&str],
+        time_to_live: Option<usize>,
+    ) -> UtilsResult<Option<Cow<'_, str>>> {
+        as_str(self)
+            .map(|s| s.replace_each_repeatedly(search_list, replace_list, time_to_live))
+            .transpose()
+    }
+
+    fn replace_first(&self, search: &str, replace: &str) -> Option<Cow<'_, str>> {
+        as_str(self).map(|s| s.replace_first(search, replace))
+    }
+
+    #[cfg(feature = "regex")]
+    fn replace_first_regex(
+        &self,
+        regex: &str,
+        replacement: &str,
+    ) -> UtilsResult<Option<Cow<'_, str>>> {
+        as_str(self)
+            .map(|s| s.replace_first_regex(regex, replacement))
+            .transpose()
+    }
+
+    #[cfg(feature = "regex")]
+    fn replace_pattern(&self, regex: &str, replacement: &str) -> UtilsResult<Option<Cow<'_, str>>> {
+        as_str(self)
+            .map(|s| s.replace_pattern(regex, replacement))
+            .transpose()
+    }
+
+    fn reverse(&self) -> Option<String> {
+        as_str(self).map(|s| s.reverse())
+    }
+
+    fn reverse_code_points(&self) -> Option<String> {
+        as_str(self).map(|s| s.reverse_code_points())
+    }
+
+    fn reverse_delimited(&self, separator_char: char) -> Option<String> {
+        as_str(self).map(|s| s.reverse_delimited(separator_char))
+    }
+
+    fn reverse_delimited_code_points(&self, separator_char: char) -> Option<String> {
+        as_str(self).map(|s| s.reverse_delimited_code_points(separator_char))
+    }
+
+    fn right(&self, len: usize) -> Option<&str> {
+        as_str(self).map(|s| s.right(len))
+    }
+
+    fn right_pad(&self, size: usize) -> Option<Cow<'_, str>> {
+        as_str(self).map(|s| s.right_pad(size))
+    }
+
+    fn right_pad_with(&self, size: usize, pad_char: char) -> Option<Cow<'_, str>> {
+        as_str(self).map(|s| s.right_pad_with(size, pad_char))
+    }
+
+    fn right_pad_with_str(&self, size: usize, pad_str: &str) -> Option<Cow<'_, str>> {
+        as_str(self).map(|s| s.right_pad_with_str(size, pad_str))
+    }
+
+    fn right_pad_to_width(&self, width: usize) -> Option<Cow<'_, str>> {
+        as_str(self).map(|s| s.right_pad_to_width(width))
+    }
+
+    fn right_pad_to_width_with(
+        &self,
+        width: usize,
+        pad_char: char,
+        policy: PadPolicy,
+    ) -> Option<Cow<'_, str>> {
+        as_str(self).map(|s| s.right_pad_to_width_with(width, pad_char, policy))
+    }
+
+    fn right_pad_to_width_with_str(
+        &self,
+        width: usize,
+        pad_str: &str,
+        policy: PadPolicy,
+    ) -> Option<Cow<'_, str>> {
+        as_str(self).map(|s| s.right_pad_to_width_with_str(width, pad_str, policy))
+    }
+
+    fn rotate(&self, shift: isize) -> Option<String> {
+        as_str(self).map(|s| s.rotate(shift))
+    }
+
+    fn rotate_code_points(&self, shift: isize) -> Option<String> {
+        as_str(self).map(|s| s.rotate_code_points(shift))
+    }
+
+    fn split_by_chars(&self, separator_chars: Option<&str>) -> Option<Vec<&str>> {
+        as_str(self).map(|s| s.split_by_chars(separator_chars))
+    }
+
+    fn split_by_chars_max(&self, separator_chars: Option<&str>, max: usize) -> Option<Vec<&str>> {
+        as_str(self).map(|s| s.split_by_chars_max(separator_chars, max))
+    }
+
+    fn split_by_character_type(&self) -> Option<Vec<&str>> {
+        as_str(self).map(|s| s.split_by_character_type())
+    }
+
+    fn split_by_character_type_and_camel_case(&self) -> Option<Vec<&str>> {
+        as_str(self).map(|s| s.split_by_character_type_and_camel_case())
+    }
+
+    fn split_by_whole_separator(&self, separator: Option<&str>) -> Option<Vec<&str>> {
+        as_str(self).map(|s| s.split_by_whole_separator(separator))
+    }
+
+    fn split_by_whole_separator_max(
+        &self,
+        separator: Option<&str>,
+        max: usize,
+    ) -> Option<Vec<&str>> {
+        as_str(self).map(|s| s.split_by_whole_separator_max(separator, max))
+    }
+
+    fn split_preserve_all_tokens(&self, separator_chars: Option<&str>) -> Option<Vec<&str>> {
+        as_str(self).map(|s| s.split_preserve_all_tokens(separator_chars))
+    }
+
+    fn split_preserve_all_tokens_max(
+        &self,
+        separator_chars: Option<&str>,
+        max: usize,
+    ) -> Option<Vec<&str>> {
+        as_str(self).map(|s| s.split_preserve_all_tokens_max(separator_chars, max))
+    }
+
+    fn starts_with(&self, prefix: &str, ignore_case: bool) -> bool {
+        as_str(self).is_some_and(|s| StringUtilsExt::starts_with(s, prefix, ignore_case))
+    }
+
+    fn starts_with_any(&self, prefixes: &[&str]) -> bool {
+        as_str(self).is_some_and(|s| s.starts_with_any(prefixes))
+    }
+
+    fn strip(&self) -> Option<&str> {
+        as_str(self).map(|s| s.strip())
+    }
+
+    fn strip_in(&self, strip_chars: &str) -> Option<&str> {
+        as_str(self).map(|s| s.strip_in(strip_chars))
+    }
+
+    fn strip_end(&self) -> Option<&str> {
+        as_str(self).map(|s| s.strip_end())
+    }
+
+    fn strip_end_in(&self, strip_chars: &str) -> Option<&str> {
+        as_str(self).map(|s| s.strip_end_in(strip_chars))
+    }
+
+    fn strip_start(&self) -> Option<&str> {
+        as_str(self).map(|s| s.strip_start())
+    }
+
+    fn strip_start_in(&self, strip_chars: &str) -> Option<&str> {
+        as_str(self).map(|s| s.strip_start_in(strip_chars))
+    }
+
+    fn strip_to_empty(&self) -> &str {
+        as_str(self).map_or(EMPTY, StringUtilsExt::strip_to_empty)
+    }
+
+    fn strip_to_none(&self) -> Option<&str> {
+        as_str(self).and_then(StringUtilsExt::strip_to_none)
+    }
+
+    fn substring<R>(&self, range: R) -> Option<&str>
+    where
+        R: RangeBounds<usize>,
+    {
+        as_str(self).map(|s| s.substring(range))
+    }
+
+    fn substring_after(&self, separator: char) -> Option<&str> {
+        as_str(self).map(|s| s.substring_after(separator))
+    }
+
+    fn substring_after_last(&self, separator: char) -> Option<&str> {
+        as_str(self).map(|s| s.substring_after_last(separator))
+    }
+
+    fn substring_after_last_str(&self, separator: &str) -> Option<&str> {
+        as_str(self).map(|s| s.substring_after_last_str(separator))
+    }
+
+    fn substring_after_str(&self, separator: &str) -> Option<&str> {
+        as_str(self).map(|s| s.substring_after_str(separator))
+    }
+
+    fn substring_before(&self, separator: char) -> Option<&str> {
+        as_str(self).map(|s| s.substring_before(separator))
+    }
+
+    fn substring_before_last(&self, separator: char) -> Option<&str> {
+        as_str(self).map(|s| s.substring_before_last(separator))
+    }
+
+    fn substring_before_last_str(&self, separator: &str) -> Option<&str> {
+        as_str(self).map(|s| s.substring_before_last_str(separator))
+    }
+
+    fn substring_before_str(&self, separator: &str) -> Option<&str> {
+        as_str(self).map(|s| s.substring_before_str(separator))
+    }
+
+    fn substring_between(&self, open: &str, close: &str) -> Option<&str> {
+        as_str(self).and_then(|s| s.substring_between(open, close))
+    }
+
+    fn substring_between_tag(&self, tag: &str) -> Option<&str> {
+        as_str(self).and_then(|s| s.substring_between_tag(tag))
+    }
+
+    fn substring_between_to_none(&self, open: &str, close: &str) -> Option<&str> {
+        as_str(self).and_then(|s| s.substring_between_to_none(open, close))
+    }
+
+    fn swap_case(&self) -> Option<String> {
+        as_str(self).map(|s| s.swap_case())
+    }
+
+    fn trim(&self) -> Option<&str> {
+        as_str(self).map(StringUtilsExt::trim)
+    }
+
+    fn trim_to_empty(&self) -> &str {
+        as_str(self).map_or(EMPTY, StringUtilsExt::trim_to_empty)
+    }
+
+    fn trim_to_none(&self) -> Option<&str> {
+        as_str(self).and_then(StringUtilsExt::trim_to_none)
+    }
+
+    fn truncate(&self, max_width: usize) -> Option<&str> {
+        as_str(self).map(|s| s.truncate(max_width))
+    }
+
+    fn truncate_with_offset(&self, offset: usize, max_width: usize) -> Option<&str> {
+        as_str(self).map(|s| s.truncate_with_offset(offset, max_width))
+    }
+
+    fn truncate_to_width(&self, max_width: usize) -> Option<&str> {
+        as_str(self).map(|s| s.truncate_to_width(max_width))
+    }
+
+    fn truncate_to_width_with_offset(&self, offset: usize, max_width: usize) -> Option<&str> {
+        as_str(self).map(|s| s.truncate_to_width_with_offset(offset, max_width))
+    }
+
+    fn uncapitalize(&self) -> Option<Cow<'_, str>> {
+        as_str(self).map(|s| s.uncapitalize())
+    }
+
+    fn unwrap_from_char(&self, wrap_char: char) -> Option<&str> {
+        as_str(self).map(|s| s.unwrap_from_char(wrap_char))
+    }
+
+    fn unwrap_from_str(&self, wrap_str: &str) -> Option<&str> {
+        as_str(self).map(|s| s.unwrap_from_str(wrap_str))
+    }
+
+    fn unwrap_quoted(&self, quote: char) -> Option<Cow<'_, str>> {
+        as_str(self).map(|s| s.unwrap_quoted(quote))
+    }
+
+    fn upper_case(&self) -> Option<String> {
+        as_str(self).map(|s| s.upper_case())
+    }
+
+    fn wrap(&self, wrap_with: char) -> Option<Cow<'_, str>> {
+        as_str(self).map(|s| s.wrap(wrap_with))
+    }
+
+    fn wrap_if_missing(&self, wrap_with: char) -> Option<Cow<'_, str>> {
+        as_str(self).map(|s| s.wrap_if_missing(wrap_with))
+    }
+
+    fn wrap_if_missing_str(&self, wrap_with: &str) -> Option<Cow<'_, str>> {
+        as_str(self).map(|s| s.wrap_if_missing_str(wrap_with))
+    }
+
+    fn wrap_with_str(&self, wrap_with: &str) -> Option<Cow<'_, str>> {
+        as_str(self).map(|s| s.wrap_with_str(wrap_with))
+    }
+}
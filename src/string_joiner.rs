@@ -0,0 +1,102 @@
+use alloc::string::String;
+use core::fmt::{self, Display, Write};
+
+/// Builds a delimited string with an optional prefix and suffix, modelled on
+/// `java.util.StringJoiner`.
+///
+/// Until something is added the joiner renders as `prefix + suffix`, or as the empty value if
+/// one was set. Adding an empty string counts as adding something.
+#[derive(Clone, Debug, Default)]
+pub struct StringJoiner {
+    delimiter: String,
+    prefix: String,
+    suffix: String,
+    empty_value: Option<String>,
+    value: Option<String>,
+}
+
+impl StringJoiner {
+    /// Creates an empty joiner that puts `delimiter` between values.
+    pub fn new(delimiter: &str) -> Self {
+        StringJoiner {
+            delimiter: String::from(delimiter),
+            ..StringJoiner::default()
+        }
+    }
+
+    /// Sets the text rendered before the joined values.
+    pub fn with_prefix(mut self, prefix: &str) -> Self {
+        self.prefix = String::from(prefix);
+        self
+    }
+
+    /// Sets the text rendered after the joined values.
+    pub fn with_suffix(mut self, suffix: &str) -> Self {
+        self.suffix = String::from(suffix);
+        self
+    }
+
+    /// Sets what to render when nothing has been added, instead of `prefix + suffix`.
+    pub fn with_empty_value(mut self, empty_value: &str) -> Self {
+        self.empty_value = Some(String::from(empty_value));
+        self
+    }
+
+    /// Appends `value`, preceded by the delimiter unless it is the first one.
+    pub fn add<T>(&mut self, value: T) -> &mut Self
+    where
+        T: Display,
+    {
+        let buffer = match &mut self.value {
+            Some(buffer) => {
+                buffer.push_str(&self.delimiter);
+                buffer
+            }
+            None => self.value.insert(String::new()),
+        };
+        // Writing into a String cannot fail.
+        let _ = write!(buffer, "{}", value);
+        self
+    }
+
+    /// Appends the contents of `other`, without its prefix and suffix, as a single value. Does
+    /// nothing if `other` is empty.
+    pub fn merge(&mut self, other: &StringJoiner) -> &mut Self {
+        if let Some(value) = &other.value {
+            self.add(value);
+        }
+        self
+    }
+
+    /// Returns `true` if nothing has been added yet.
+    pub fn is_empty(&self) -> bool {
+        self.value.is_none()
+    }
+}
+
+impl Display for StringJoiner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.value, &self.empty_value) {
+            (None, Some(empty_value)) => f.write_str(empty_value),
+            (value, _) => {
+                f.write_str(&self.prefix)?;
+                f.write_str(value.as_deref().unwrap_or_default())?;
+                f.write_str(&self.suffix)
+            }
+        }
+    }
+}
+
+impl<T> Extend<T> for StringJoiner
+where
+    T: Display,
+{
+    fn extend<I>(&mut self, values: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        for value in values {
+            self.add(value);
+        }
+    }
+}
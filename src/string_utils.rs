@@ -1,1032 +1,1738 @@
-use crate::error::UtilsError::InvalidArgument;
+use crate::case_tables::{SIMPLE_CASE_FOLDING, TITLECASE_EXCEPTIONS};
+use crate::error::UtilsError::{InvalidArgument, ReplacementCycle};
 use crate::error::UtilsResult;
-use std::fmt::Display;
-use std::ops::{Deref, Range, RangeBounds};
-
+use crate::string_joiner::StringJoiner;
+use alloc::borrow::Cow;
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
+use core::cmp::Ordering;
+use core::fmt::Display;
+use core::ops::{Bound, Range, RangeBounds};
+#[cfg(feature = "regex")]
+use regex::{Regex, RegexBuilder};
+use unicode_general_category::{get_general_category, GeneralCategory};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+/// Extension methods ported from Apache Commons Lang3 `StringUtils`.
+///
+/// Every position taken or returned by these methods is a char offset, that is a count of
+/// Unicode scalar values, never a byte offset. An index returned by [`index_of`] can be passed
+/// straight back into [`index_of_starting_from`] or [`substring`] on any UTF-8 input.
+///
+/// Results that are always a slice of the input are returned as `&str`. Results that are often
+/// the input unchanged are returned as a `Cow<str>` that borrows it in that case, so no-op calls
+/// don't allocate.
+///
+/// The trait is implemented for every `AsRef<str>` type, such as `str`, `String`, `Cow<str>`,
+/// `Box<str>`, `Rc<str>` and `Arc<str>`. On a `&str`, the inherent methods of `str` win over
+/// the ones sharing their name (`contains`, `is_empty`, `repeat`, `starts_with` and `trim`), so
+/// call those as `StringUtilsExt::trim(s)`.
+///
+/// [`index_of`]: StringUtilsExt::index_of
+/// [`index_of_starting_from`]: StringUtilsExt::index_of_starting_from
+/// [`substring`]: StringUtilsExt::substring
 pub trait StringUtilsExt {
-    fn abbreviate(&self, max_width: usize) -> UtilsResult<String>;
+    /// Shortens the string to at most `max_width` chars, ending it with `"..."`.
+    ///
+    /// Fails if `max_width` is less than 4 and the string is too long.
+    fn abbreviate(&self, max_width: usize) -> UtilsResult<Cow<'_, str>>;
+    /// Like [`abbreviate`](StringUtilsExt::abbreviate), but ends the string with
+    /// `abbrev_marker`. An empty marker simply cuts the string at `max_width` chars.
     fn abbreviate_with_abbrev_marker(
         &self,
         abbrev_marker: &str,
         max_width: usize,
-    ) -> UtilsResult<String>;
+    ) -> UtilsResult<Cow<'_, str>>;
+    /// Shortens the string to at most `max_width` chars so that the char at `offset` is kept,
+    /// putting `abbrev_marker` on each side that was cut. Widths and offsets count chars,
+    /// markers included.
+    ///
+    /// Fails if `max_width` leaves no room for a char besides the markers it needs.
     fn abbreviate_with_full_opt(
         &self,
         abbrev_marker: &str,
         offset: isize,
         max_width: usize,
-    ) -> UtilsResult<String>;
-    fn abbreviate_middle(&self, middle: &str, length: usize) -> UtilsResult<String>;
-    fn abbreviate_with_offset(&self, offset: isize, max_width: usize) -> UtilsResult<String>;
-    fn append_if_missing(&self, suffix: &str, ignore_case: bool) -> String;
+    ) -> UtilsResult<Cow<'_, str>>;
+    /// Shortens the string to `length` chars by replacing its middle with `middle`. Returns the
+    /// string unchanged if it already fits, if either is empty, or if `length` leaves no room
+    /// for a char on each side of `middle`.
+    fn abbreviate_middle(&self, middle: &str, length: usize) -> Cow<'_, str>;
+    /// Like [`abbreviate_with_full_opt`](StringUtilsExt::abbreviate_with_full_opt) with the
+    /// `"..."` marker.
+    fn abbreviate_with_offset(&self, offset: isize, max_width: usize) -> UtilsResult<Cow<'_, str>>;
+    /// Like [`abbreviate`](StringUtilsExt::abbreviate), but `max_width` counts display columns
+    /// as measured by [`display_width`](StringUtilsExt::display_width). A wide char that would
+    /// cross the limit is dropped whole, so the result may be a column short.
+    fn abbreviate_to_width(&self, max_width: usize) -> UtilsResult<Cow<'_, str>>;
+    /// Like [`abbreviate_with_abbrev_marker`](StringUtilsExt::abbreviate_with_abbrev_marker),
+    /// but `max_width` counts display columns, the marker's included.
+    fn abbreviate_to_width_with_abbrev_marker(
+        &self,
+        abbrev_marker: &str,
+        max_width: usize,
+    ) -> UtilsResult<Cow<'_, str>>;
+    /// Like [`abbreviate_with_full_opt`](StringUtilsExt::abbreviate_with_full_opt), but
+    /// `max_width` counts display columns, the markers' included. `offset` is still a char
+    /// offset.
+    fn abbreviate_to_width_with_full_opt(
+        &self,
+        abbrev_marker: &str,
+        offset: isize,
+        max_width: usize,
+    ) -> UtilsResult<Cow<'_, str>>;
+    /// Appends `suffix` unless the string already ends with it. Borrows the input when nothing
+    /// is appended.
+    fn append_if_missing(&self, suffix: &str, ignore_case: bool) -> Cow<'_, str>;
+    /// Appends `suffix` unless the string already ends with it or with one of `suffixes`.
     fn append_if_missing_one_of(
         &self,
         suffix: &str,
         suffixes: &[&str],
         ignore_case: bool,
-    ) -> String;
-    fn capitalize(&self) -> String;
-    fn center(&self, size: usize) -> String;
-    fn center_with(&self, size: usize, pad_str: &str) -> String;
-    fn center_with_char(&self, size: usize, pad_char: &str) -> String;
-    fn chomp(&self) -> String;
-    fn chomp_specified(&self, separator: &str) -> String;
-    fn chop(&self) -> String;
-    fn compare(&self, str2: &str) -> i32;
-    fn compare_ignore_case(&self, str2: &str) -> i32;
+    ) -> Cow<'_, str>;
+    /// Converts the first char to titlecase, leaving the rest unchanged: `'ǆ'` becomes `'ǅ'`
+    /// rather than `'Ǆ'`.
+    ///
+    /// Full case mappings are used, so the result may have more chars than the input:
+    /// `"ßa".capitalize()` is `"Ssa"`.
+    fn capitalize(&self) -> Cow<'_, str>;
+    /// Centers the string in `size` chars by padding both sides with spaces. When the padding is
+    /// uneven the extra char goes on the right.
+    fn center(&self, size: usize) -> Cow<'_, str>;
+    /// Centers the string in `size` chars by padding both sides with `pad_str`, repeated and cut
+    /// as needed. An empty `pad_str` pads with spaces.
+    fn center_with(&self, size: usize, pad_str: &str) -> Cow<'_, str>;
+    fn center_with_char(&self, size: usize, pad_char: char) -> Cow<'_, str>;
+    /// Centers the string in `width` display columns by padding both sides with spaces, as
+    /// measured by [`display_width`](StringUtilsExt::display_width). When the padding is uneven
+    /// the extra column goes on the right.
+    fn center_to_width(&self, width: usize) -> Cow<'_, str>;
+    /// Centers the string in `width` display columns by padding both sides with `pad_str`,
+    /// following `policy` where a wide pad grapheme doesn't fit. An empty or zero-width
+    /// `pad_str` pads with spaces.
+    fn center_to_width_with(&self, width: usize, pad_str: &str, policy: PadPolicy) -> Cow<'_, str>;
+    fn center_to_width_with_char(
+        &self,
+        width: usize,
+        pad_char: char,
+        policy: PadPolicy,
+    ) -> Cow<'_, str>;
+    /// Removes one trailing line ending: `"\r\n"`, `"\n"` or `"\r"`. Only the last one goes,
+    /// so `"abc\n\r"` becomes `"abc\n"`.
+    fn chomp(&self) -> &str;
+    /// Removes `separator` from the end of the string if it is there.
+    fn chomp_specified(&self, separator: &str) -> &str;
+    /// Removes the last char, or both chars of a trailing `"\r\n"`.
+    fn chop(&self) -> &str;
+    /// Compares the two strings lexicographically by code point. Java compares UTF-16 code
+    /// units instead, which orders chars above `'\u{FFFF}'` differently.
+    ///
+    /// See [`StringUtils::compare_nulls_first`] and friends for comparing optional strings.
+    fn compare(&self, str2: &str) -> Ordering;
+    /// Compares the two strings lexicographically after simple case folding each char, so
+    /// `"abc"` and `"ABC"` are equal.
+    fn compare_ignore_case(&self, str2: &str) -> Ordering;
     fn contains(&self, search: &str) -> bool;
     fn contains_char(&self, search_char: char) -> bool;
     fn contains_any(&self, searches: &[&str]) -> bool;
     fn contains_any_char(&self, searches: &[char]) -> bool;
     fn contains_any_in(&self, searches_str: &str) -> bool;
+    /// Tests whether any of `searches` occurs in the string, comparing chars case-insensitively
+    /// by their full Unicode upper and lower case mappings.
     fn contains_any_ignore_case(&self, searches: &[&str]) -> bool;
+    /// Tests whether `test(self, search)` holds for any of `searches`, which allows custom
+    /// matchers such as accent-insensitive or fuzzy comparisons. Returns `false` if the string or
+    /// `searches` is empty.
     fn contains_any_with<T>(&self, searches: &[&str], test: T) -> bool
     where
         T: Fn(&str, &str) -> bool;
+    /// Tests whether `search` occurs in the string, comparing chars case-insensitively by their
+    /// full Unicode upper and lower case mappings, so `"ΑΒΓ"` contains `"βγ"`.
     fn contains_ignore_case(&self, search: &str) -> bool;
     fn contains_none(&self, searches: &[char]) -> bool;
     fn contains_none_in(&self, invalid_chars: &str) -> bool;
+    /// Tests whether every char is in `valid`. An empty string always passes.
     fn contains_only(&self, valid: &[char]) -> bool;
     fn contains_only_in(&self, valid_chars: &str) -> bool;
+    /// Tests whether the string contains a char with the Unicode `White_Space` property.
     fn contains_whitespace(&self) -> bool;
+    /// Counts the occurrences of `ch`.
     fn count_matches(&self, ch: char) -> u64;
+    /// Counts the non-overlapping occurrences of `sub`. An empty `sub` counts as `0`.
     fn count_matches_str(&self, sub: &str) -> u64;
-    fn default_if_blank(&self, default_str: &str) -> String;
-    fn default_if_empty(&self, default_str: &str) -> String;
-    fn default_string(&self) -> String;
-    fn delete_whitespace(&self) -> String;
-    fn difference(&self, str2: &str) -> String;
+    /// Returns `default_str` if the string is blank, and the string itself otherwise.
+    fn default_if_blank<'a>(&'a self, default_str: &'a str) -> &'a str;
+    /// Returns `default_str` if the string is empty, and the string itself otherwise.
+    fn default_if_empty<'a>(&'a self, default_str: &'a str) -> &'a str;
+    /// Returns the string itself; a non-null string is its own default in Commons Lang.
+    fn default_string(&self) -> &str;
+    /// Removes every Unicode `White_Space` char. Borrows the input when there is none.
+    fn delete_whitespace(&self) -> Cow<'_, str>;
+    /// Like [`delete_whitespace`](StringUtilsExt::delete_whitespace), but returns `None` if
+    /// what's left is empty or [blank](StringUtilsExt::is_blank).
+    fn delete_whitespace_to_none(&self) -> Option<Cow<'_, str>>;
+    /// Returns the rest of `str2` from the first char where it differs from this string, or an
+    /// empty string if they are equal.
+    fn difference<'a>(&self, str2: &'a str) -> &'a str;
+    /// Keeps only the decimal digits of the string, in any script.
     fn digits(&self) -> String;
+    /// Returns the number of terminal columns the string takes up: East Asian Wide and Fullwidth
+    /// chars and emoji count 2, zero-width chars such as combining marks count 0, and other chars
+    /// count 1. The widths of the grapheme clusters are added up, so an emoji sequence counts
+    /// once.
+    fn display_width(&self) -> usize;
+    /// Tests whether the string ends with `suffix`, optionally ignoring case. An empty `suffix`
+    /// always matches.
     fn end_with(&self, suffix: &str, ignore_case: bool) -> bool;
+    /// Tests whether the string ends with any of `searches`. An empty string matches nothing.
     fn end_with_any(&self, searches: &[&str]) -> bool;
+    /// Case-sensitive [`end_with`](StringUtilsExt::end_with).
     fn end_with_consider_case(&self, suffix: &str) -> bool;
+    /// Case-insensitive [`end_with`](StringUtilsExt::end_with).
     fn end_with_ignore_case(&self, suffix: &str) -> bool;
     fn equals(&self, str2: &str) -> bool;
     fn equals_any(&self, searches: &[&str]) -> bool;
     fn equals_any_ignore_case(&self, searches: &[&str]) -> bool;
+    /// Compares with `str2` char by char, ignoring case like Java's `equalsIgnoreCase`.
     fn equals_ignore_case(&self, str2: &str) -> bool;
-    fn if_blank<T>(&self, default_supplier: T) -> String
+    /// Returns the result of `default_supplier` if the string is blank, and borrows the string
+    /// otherwise. The supplier is only called when needed.
+    fn if_blank<T>(&self, default_supplier: T) -> Cow<'_, str>
     where
-        T: Fn() -> String;
-    fn if_empty<T>(&self, default_supplier: T) -> String
+        T: FnOnce() -> String;
+    /// Returns the result of `default_supplier` if the string is empty, and borrows the string
+    /// otherwise. The supplier is only called when needed.
+    fn if_empty<T>(&self, default_supplier: T) -> Cow<'_, str>
     where
-        T: Fn() -> String;
+        T: FnOnce() -> String;
+    /// Finds the first occurrence of `search`. An empty `search` matches at `0`.
     fn index_of(&self, search: &str) -> Option<usize>;
+    /// Finds the earliest occurrence of any of `searches`. An empty search matches at `0`.
     fn index_of_any(&self, searches: &[&str]) -> Option<usize>;
+    /// Finds the first char that is not in `search_chars`. Returns `None` if either side is empty.
     fn index_of_any_but(&self, search_chars: &[char]) -> Option<usize>;
     fn index_of_any_but_in(&self, search_chars: &str) -> Option<usize>;
+    /// Finds the first char that is in `search_chars`.
     fn index_of_any_char(&self, search_chars: &[char]) -> Option<usize>;
     fn index_of_any_in(&self, search_chars: &str) -> Option<usize>;
     fn index_of_char(&self, search_char: char) -> Option<usize>;
+    /// Finds the first `search_char` at or after `start_pos`. Returns `None` if `start_pos` is
+    /// past the end.
     fn index_of_char_starting_from(&self, search_char: char, start_pos: usize) -> Option<usize>;
+    /// Finds the first occurrence of `search` at or after `start_pos`. A `start_pos` past the end
+    /// is treated as the end, so an empty `search` then matches at the char length.
     fn index_of_starting_from(&self, search: &str, start_pos: usize) -> Option<usize>;
+    /// Returns the offset of the first char that differs from `str2`, or `None` if they are equal.
     fn index_of_difference(&self, str2: &str) -> Option<usize>;
     fn index_of_ignore_case(&self, search: &str) -> Option<usize>;
+    /// Case-insensitive [`index_of_starting_from`](StringUtilsExt::index_of_starting_from).
+    /// Unlike it, a `start_pos` past the last possible match returns `None`, even for an empty
+    /// `search`.
     fn index_of_ignore_case_starting_from(&self, search: &str, start_pos: usize) -> Option<usize>;
+    /// Tests whether the string is non-empty and only contains letters, that is chars in the
+    /// Unicode general categories `Lu`, `Ll`, `Lt`, `Lm` and `Lo` as in Java's
+    /// `Character.isLetter`. Combining marks are not letters.
     fn is_alpha(&self) -> bool;
+    /// Tests whether the string is non-empty and only contains letters or decimal digits (`Nd`)
+    /// of any script.
     fn is_alphanumeric(&self) -> bool;
+    /// Tests whether the string only contains letters, decimal digits of any script or `' '`.
+    /// An empty string passes.
     fn is_alphanumeric_space(&self) -> bool;
+    /// Tests whether the string only contains letters or `' '`. An empty string passes.
     fn is_alpha_space(&self) -> bool;
+    /// ASCII-only [`is_alpha`](StringUtilsExt::is_alpha): only `a-z` and `A-Z` count.
+    fn is_ascii_alpha(&self) -> bool;
+    /// ASCII-only [`is_alphanumeric`](StringUtilsExt::is_alphanumeric): only `a-z`, `A-Z` and
+    /// `0-9` count.
+    fn is_ascii_alphanumeric(&self) -> bool;
+    /// ASCII-only [`is_alphanumeric_space`](StringUtilsExt::is_alphanumeric_space).
+    fn is_ascii_alphanumeric_space(&self) -> bool;
+    /// ASCII-only [`is_alpha_space`](StringUtilsExt::is_alpha_space).
+    fn is_ascii_alpha_space(&self) -> bool;
+    /// ASCII-only [`is_numeric`](StringUtilsExt::is_numeric): only `0-9` count.
+    fn is_ascii_numeric(&self) -> bool;
+    /// ASCII-only [`is_numeric_space`](StringUtilsExt::is_numeric_space).
+    fn is_ascii_numeric_space(&self) -> bool;
+    /// Tests whether every char is in `' '..='~'`. An empty string passes.
     fn is_ascii_printable(&self) -> bool;
+    /// Tests whether the string is empty or only contains whitespace as defined by Java's
+    /// `Character.isWhitespace`: no-break spaces such as `'\u{A0}'` and `'\u{85}'` don't count,
+    /// but the separators `'\u{1C}'..='\u{1F}'` do.
     fn is_blank(&self) -> bool;
     fn is_empty(&self) -> bool;
+    /// Tests whether the string contains both an uppercase and a lowercase char. Titlecase
+    /// letters such as `'ǅ'` count as neither, as in Java.
     fn is_mixed_case(&self) -> bool;
     fn is_not_blank(&self) -> bool;
     fn is_not_empty(&self) -> bool;
+    /// Tests whether the string is non-empty and only contains decimal digits (`Nd`) of any
+    /// script, so `"१२३"` is numeric. Signs, decimal points and other numbers such as `'½'` are
+    /// not.
     fn is_numeric(&self) -> bool;
+    /// Tests whether the string only contains decimal digits of any script or `' '`. An empty
+    /// string passes.
     fn is_numeric_space(&self) -> bool;
+    /// Tests whether the string only contains whitespace as defined by Java's
+    /// `Character.isWhitespace`, like [`is_blank`](StringUtilsExt::is_blank). An empty string
+    /// passes.
     fn is_whitespace(&self) -> bool;
+    /// Finds the last occurrence of `search`. An empty `search` matches at the char length.
     fn last_index_of(&self, search: &str) -> Option<usize>;
+    /// Finds the latest occurrence of any of `searches`. An empty search matches at the char
+    /// length.
     fn last_index_of_any(&self, searches: &[&str]) -> Option<usize>;
     fn last_index_of_char(&self, search_char: char) -> Option<usize>;
+    /// Finds the last `search_char` at or before `start_pos`. A `start_pos` past the end searches
+    /// the whole string.
     fn last_index_of_char_starting_from(
         &self,
         search_char: char,
         start_pos: usize,
     ) -> Option<usize>;
     fn last_index_of_ignore_case(&self, search: &str) -> Option<usize>;
+    /// Case-insensitive [`last_index_of_starting_from`](StringUtilsExt::last_index_of_starting_from).
     fn last_index_of_ignore_case_starting_from(
         &self,
         search: &str,
         start_pos: usize,
     ) -> Option<usize>;
+    /// Finds the last occurrence of `search` that starts at or before `start_pos`. A `start_pos`
+    /// past the end searches the whole string.
     fn last_index_of_starting_from(&self, search: &str, start_pos: usize) -> Option<usize>;
+    /// Finds the `ordinal`-th (1-based) occurrence of `search` counting from the end. Occurrences
+    /// may overlap. An `ordinal` of `0` never matches and an empty `search` matches at the char
+    /// length.
     fn last_ordinal_index_of(&self, search: &str, ordinal: usize) -> Option<usize>;
-    fn left(&self, len: usize) -> String;
-    fn left_pad(&self, size: usize) -> String;
-    fn left_pad_with(&self, size: usize, pad_char: char) -> String;
-    fn left_pad_with_str(&self, size: usize, pad_str: &str) -> String;
+    /// Returns the leftmost `len` chars, or the whole string if it is shorter.
+    fn left(&self, len: usize) -> &str;
+    /// Left pads the string with spaces up to `size` chars. A string that is already long enough
+    /// is returned unchanged.
+    fn left_pad(&self, size: usize) -> Cow<'_, str>;
+    fn left_pad_with(&self, size: usize, pad_char: char) -> Cow<'_, str>;
+    /// Left pads the string with `pad_str` up to `size` chars, repeating `pad_str` and cutting the
+    /// last repetition short as needed, so `"bat".left_pad_with_str(8, "yz")` is `"yzyzybat"`.
+    /// An empty `pad_str` pads with spaces.
+    fn left_pad_with_str(&self, size: usize, pad_str: &str) -> Cow<'_, str>;
+    /// Left pads the string with spaces up to `width` display columns, as measured by
+    /// [`display_width`](StringUtilsExt::display_width). A string that is already wide enough is
+    /// returned unchanged.
+    fn left_pad_to_width(&self, width: usize) -> Cow<'_, str>;
+    fn left_pad_to_width_with(
+        &self,
+        width: usize,
+        pad_char: char,
+        policy: PadPolicy,
+    ) -> Cow<'_, str>;
+    /// Left pads the string with `pad_str` up to `width` display columns, repeating `pad_str`
+    /// grapheme by grapheme and following `policy` where a wide grapheme doesn't fit, so
+    /// `"ab".left_pad_to_width_with_str(5, "中", PadPolicy::FillWithSpace)` is `"中 ab"`. An empty
+    /// or zero-width `pad_str` pads with spaces.
+    fn left_pad_to_width_with_str(
+        &self,
+        width: usize,
+        pad_str: &str,
+        policy: PadPolicy,
+    ) -> Cow<'_, str>;
+    /// Converts every char to lowercase using full case mappings, so the result may differ in
+    /// length from the input: `"İ".lower_case()` is `"i\u{307}"`.
     fn lower_case(&self) -> String;
-    fn mid(&self, pos: usize, len: usize) -> String;
-    fn normalize_space(&self) -> String;
+    /// Returns `len` chars starting at `pos`, or fewer if the string ends first.
+    fn mid(&self, pos: usize, len: usize) -> &str;
+    /// Strips whitespace from both ends and replaces each run of Unicode `White_Space` chars
+    /// inside with a single space. Borrows the input when it is already normalized.
+    fn normalize_space(&self) -> Cow<'_, str>;
+    /// Like [`normalize_space`](StringUtilsExt::normalize_space), but returns `None` if what's
+    /// left is empty or [blank](StringUtilsExt::is_blank).
+    fn normalize_space_to_none(&self) -> Option<Cow<'_, str>>;
+    /// Finds the `ordinal`-th (1-based) occurrence of `search`. Occurrences may overlap, so
+    /// `"aaaa".ordinal_index_of("aa", 2)` is `Some(1)`. An `ordinal` of `0` never matches and an
+    /// empty `search` matches at `0`.
     fn ordinal_index_of(&self, search: &str, ordinal: usize) -> Option<usize>;
+    /// Replaces the chars from `start` to `end` with `overlay`. Both offsets are clamped to the
+    /// length of the string and swapped if `start` is after `end`.
     fn overlay(&self, overlay: &str, start: usize, end: usize) -> String;
-    fn prepend_if_missing(&self, prefix: &str, ignore_case: bool) -> String;
+    /// Prepends `prefix` unless the string already starts with it. Borrows the input when
+    /// nothing is prepended.
+    fn prepend_if_missing(&self, prefix: &str, ignore_case: bool) -> Cow<'_, str>;
+    /// Prepends `prefix` unless the string already starts with it or with one of `prefixes`.
     fn prepend_if_missing_one_of(
         &self,
         prefix: &str,
         prefixes: &[&str],
         ignore_case: bool,
-    ) -> String;
-    fn remove(&self, remove: &str) -> String;
-    fn remove_char(&self, remove: char) -> String;
-    fn remove_all(&self, remove: &str) -> String;
+    ) -> Cow<'_, str>;
+    /// Removes every occurrence of `remove`. Borrows the input when nothing matches.
+    fn remove(&self, remove: &str) -> Cow<'_, str>;
+    /// Like [`remove`](StringUtilsExt::remove), but returns `None` when nothing was removed.
+    fn remove_checked(&self, remove: &str) -> Option<String>;
+    /// Removes every occurrence of `remove`. Borrows the input when nothing matches.
+    fn remove_char(&self, remove: char) -> Cow<'_, str>;
+    /// Like [`remove_char`](StringUtilsExt::remove_char), but returns `None` when nothing was
+    /// removed.
+    fn remove_char_checked(&self, remove: char) -> Option<String>;
+    /// Same as [`remove`](StringUtilsExt::remove); `remove` is matched literally.
+    fn remove_all(&self, remove: &str) -> Cow<'_, str>;
+    /// Removes every match of `regex`. As in Java without `DOTALL`, `.` doesn't match `'\n'`;
+    /// see [`remove_pattern`](StringUtilsExt::remove_pattern).
     #[cfg(feature = "regex")]
-    fn remove_all_regex(&self, regex: &str) -> String;
-    fn remove_end(&self, remove: &str) -> String;
-    fn remove_end_ignore_case(&self, remove: &str) -> String;
-    fn remove_first(&self, remove: &str) -> String;
+    fn remove_all_regex(&self, regex: &str) -> UtilsResult<Cow<'_, str>>;
+    /// Removes `remove` from the end if it is there. Never allocates.
+    fn remove_end(&self, remove: &str) -> &str;
+    /// Like [`remove_end`](StringUtilsExt::remove_end), but returns `None` when nothing was
+    /// removed.
+    fn remove_end_checked(&self, remove: &str) -> Option<&str>;
+    /// Case-insensitive [`remove_end`](StringUtilsExt::remove_end).
+    fn remove_end_ignore_case(&self, remove: &str) -> &str;
+    /// Like [`remove_end_ignore_case`](StringUtilsExt::remove_end_ignore_case), but returns
+    /// `None` when nothing was removed.
+    fn remove_end_ignore_case_checked(&self, remove: &str) -> Option<&str>;
+    /// Removes the first occurrence of `remove`. Borrows the input when nothing matches.
+    fn remove_first(&self, remove: &str) -> Cow<'_, str>;
+    /// Like [`remove_first`](StringUtilsExt::remove_first), but returns `None` when nothing was
+    /// removed.
+    fn remove_first_checked(&self, remove: &str) -> Option<String>;
+    /// Removes the first match of `regex`, in which `.` doesn't match `'\n'`.
     #[cfg(feature = "regex")]
-    fn remove_first_regex(&self, regex: &str) -> String;
-    fn remove_ignore_case(&self, remove: &str) -> String;
+    fn remove_first_regex(&self, regex: &str) -> UtilsResult<Cow<'_, str>>;
+    /// Case-insensitive [`remove`](StringUtilsExt::remove).
+    fn remove_ignore_case(&self, remove: &str) -> Cow<'_, str>;
+    /// Like [`remove_ignore_case`](StringUtilsExt::remove_ignore_case), but returns `None` when
+    /// nothing was removed.
+    fn remove_ignore_case_checked(&self, remove: &str) -> Option<String>;
+    /// Removes every match of `regex` compiled with Java's `DOTALL` semantics, so `.` also
+    /// matches `'\n'`.
     #[cfg(feature = "regex")]
-    fn remove_pattern(&self, regex: &str) -> String;
-    fn remove_start(&self, remove: &str) -> String;
-    fn remove_start_char(&self, remove: char) -> String;
-    fn remove_start_ignore_case(&self, remove: &str) -> String;
+    fn remove_pattern(&self, regex: &str) -> UtilsResult<Cow<'_, str>>;
+    /// Removes `remove` from the start if it is there. Never allocates.
+    fn remove_start(&self, remove: &str) -> &str;
+    /// Like [`remove_start`](StringUtilsExt::remove_start), but returns `None` when nothing was
+    /// removed.
+    fn remove_start_checked(&self, remove: &str) -> Option<&str>;
+    /// Removes `remove` from the start if it is there. Never allocates.
+    fn remove_start_char(&self, remove: char) -> &str;
+    /// Like [`remove_start_char`](StringUtilsExt::remove_start_char), but returns `None` when
+    /// nothing was removed.
+    fn remove_start_char_checked(&self, remove: char) -> Option<&str>;
+    /// Case-insensitive [`remove_start`](StringUtilsExt::remove_start).
+    fn remove_start_ignore_case(&self, remove: &str) -> &str;
+    /// Like [`remove_start_ignore_case`](StringUtilsExt::remove_start_ignore_case), but returns
+    /// `None` when nothing was removed.
+    fn remove_start_ignore_case_checked(&self, remove: &str) -> Option<&str>;
     fn repeat(&self, repeat: usize) -> String;
-    fn replace_chars(&self, search: char, replace: char) -> String;
-    fn replace_chars_in(&self, search_chars: &str, replace_chars: &str) -> String;
-    fn replace_each(&self, search_list: &[&str], replace_list: &[&str]) -> UtilsResult<String>;
+    /// Replaces every match of `regex` with `replacement`, in which `.` doesn't match `'\n'`.
+    /// See [`replace_first_regex`](StringUtilsExt::replace_first_regex) for the `replacement`
+    /// syntax.
+    #[cfg(feature = "regex")]
+    fn replace_all_regex(&self, regex: &str, replacement: &str) -> UtilsResult<Cow<'_, str>>;
+    /// Replaces every `search` char with `replace`. Borrows the input when there is none.
+    fn replace_chars(&self, search: char, replace: char) -> Cow<'_, str>;
+    /// Replaces each char found in `search_chars` with the char at the same position in
+    /// `replace_chars`, or deletes it if `replace_chars` is shorter. Borrows the input when no
+    /// char matches.
+    fn replace_chars_in(&self, search_chars: &str, replace_chars: &str) -> Cow<'_, str>;
+    /// Replaces every occurrence of each `search_list[i]` with `replace_list[i]` in a single pass.
+    /// Matches never overlap: at each position the earliest match wins, and on a tie the one
+    /// listed first. Replaced text is not searched again and empty search strings are skipped.
+    ///
+    /// Returns [`InvalidArgument`](crate::error::UtilsError::InvalidArgument) if the lists differ
+    /// in length, unless the string or either list is empty.
+    fn replace_each(
+        &self,
+        search_list: &[&str],
+        replace_list: &[&str],
+    ) -> UtilsResult<Cow<'_, str>>;
+    /// Repeats [`replace_each`](StringUtilsExt::replace_each) until nothing matches, for at most
+    /// `time_to_live` passes (by default one more than the number of search strings, like Commons
+    /// Lang).
+    ///
+    /// Returns [`ReplacementCycle`](crate::error::UtilsError::ReplacementCycle) naming the search
+    /// strings involved as soon as a pass gives back a text seen before, such as with `"a" -> "b"`
+    /// and `"b" -> "a"`, or a replacement leads back to itself through the search strings found
+    /// inside it, such as `"a" -> "aa"`. The same happens if text still matches after the last
+    /// pass and one of the matching search strings leads to such a cycle. Otherwise the result of
+    /// the last pass is returned.
     fn replace_each_repeatedly(
         &self,
         search_list: &[&str],
         replace_list: &[&str],
         time_to_live: Option<usize>,
-    ) -> UtilsResult<String>;
-    fn replace_first(&self, search: &str, replace: &str) -> String;
+    ) -> UtilsResult<Cow<'_, str>>;
+    /// Replaces the first occurrence of `search` with `replace`. Borrows the input when nothing
+    /// matches or `search` is empty.
+    fn replace_first(&self, search: &str, replace: &str) -> Cow<'_, str>;
+    /// Replaces the first match of `regex` with `replacement`, in which `.` doesn't match
+    /// `'\n'`.
+    ///
+    /// `replacement` uses the `regex` crate syntax: `$1` or `${name}` refer to groups and `$$`
+    /// is a literal `$`. Unlike Java, `$1a` names the group `1a`; write `${1}a` instead.
+    #[cfg(feature = "regex")]
+    fn replace_first_regex(&self, regex: &str, replacement: &str) -> UtilsResult<Cow<'_, str>>;
+    /// Replaces every match of `regex` with `replacement`, compiling `regex` with Java's
+    /// `DOTALL` semantics so `.` also matches `'\n'`. See
+    /// [`replace_first_regex`](StringUtilsExt::replace_first_regex) for the `replacement`
+    /// syntax.
     #[cfg(feature = "regex")]
-    fn replace_first_regex(&self, search: &str, replace: &str) -> String;
+    fn replace_pattern(&self, regex: &str, replacement: &str) -> UtilsResult<Cow<'_, str>>;
+    /// Reverses the order of the extended grapheme clusters, so flags, combining accents and
+    /// ZWJ emoji sequences stay intact: `"noe\u{308}l".reverse()` is `"le\u{308}on"`.
     fn reverse(&self) -> String;
+    /// Reverses the order of the chars, like Java's `StringUtils.reverse`. Combining marks end
+    /// up on the wrong base char; see [`reverse`](StringUtilsExt::reverse).
+    fn reverse_code_points(&self) -> String;
+    /// Reverses the order of the fields delimited by `separator_char`: `"a.b.c"` becomes
+    /// `"c.b.a"`. As in Commons Lang, adjacent separators count as one and empty fields are
+    /// dropped.
+    ///
+    /// Only a `separator_char` that is a grapheme cluster on its own separates fields, so one
+    /// carrying a combining mark stays inside its field.
     fn reverse_delimited(&self, separator_char: char) -> String;
-    fn right(&self, len: usize) -> String;
-    fn right_pad(&self, size: usize) -> String;
-    fn right_pad_with(&self, size: usize, pad_char: char) -> String;
-    fn right_pad_with_str(&self, size: usize, pad_str: &str) -> String;
+    /// Like [`reverse_delimited`](StringUtilsExt::reverse_delimited), but splits at every
+    /// `separator_char` regardless of grapheme clusters.
+    fn reverse_delimited_code_points(&self, separator_char: char) -> String;
+    /// Returns the rightmost `len` chars, or the whole string if it is shorter.
+    fn right(&self, len: usize) -> &str;
+    /// Right pads the string with spaces up to `size` chars. A string that is already long enough
+    /// is returned unchanged.
+    fn right_pad(&self, size: usize) -> Cow<'_, str>;
+    fn right_pad_with(&self, size: usize, pad_char: char) -> Cow<'_, str>;
+    /// Right pads the string with `pad_str` up to `size` chars, repeating `pad_str` and cutting
+    /// the last repetition short as needed. An empty `pad_str` pads with spaces.
+    fn right_pad_with_str(&self, size: usize, pad_str: &str) -> Cow<'_, str>;
+    /// Right pads the string with spaces up to `width` display columns, as measured by
+    /// [`display_width`](StringUtilsExt::display_width). A string that is already wide enough is
+    /// returned unchanged.
+    fn right_pad_to_width(&self, width: usize) -> Cow<'_, str>;
+    fn right_pad_to_width_with(
+        &self,
+        width: usize,
+        pad_char: char,
+        policy: PadPolicy,
+    ) -> Cow<'_, str>;
+    /// Right pads the string with `pad_str` up to `width` display columns, repeating `pad_str`
+    /// grapheme by grapheme and following `policy` where a wide grapheme doesn't fit. An empty
+    /// or zero-width `pad_str` pads with spaces.
+    fn right_pad_to_width_with_str(
+        &self,
+        width: usize,
+        pad_str: &str,
+        policy: PadPolicy,
+    ) -> Cow<'_, str>;
+    /// Circularly shifts the extended grapheme clusters `shift` places to the right, or to the
+    /// left if `shift` is negative: `"abcdefg".rotate(2)` is `"fgabcde"`.
     fn rotate(&self, shift: isize) -> String;
-    // fn split_by_character_type(&self) -> String;
-    // fn split_by_character_type_and_camel_case(&self, camel_case: bool) -> String;
+    /// Like [`rotate`](StringUtilsExt::rotate), but shifts chars, like Java's
+    /// `StringUtils.rotate`.
+    fn rotate_code_points(&self, shift: isize) -> String;
+    /// Splits the string at every char found in `separator_chars`, or at whitespace as defined
+    /// by [`is_blank`](StringUtilsExt::is_blank) if it is `None`, so a `'\u{A0}'` doesn't split
+    /// but `'\u{1C}'` does. Like Commons Lang's `split`, adjacent separators count as one, and
+    /// leading or trailing separators produce no empty tokens: `"::a::b:"` split on `":"` is
+    /// `["a", "b"]`. An empty string gives an empty `Vec`.
+    fn split_by_chars(&self, separator_chars: Option<&str>) -> Vec<&str>;
+    /// Like [`split_by_chars`](StringUtilsExt::split_by_chars), but returns at most `max`
+    /// tokens, the last holding the rest of the string: `"ab:cd:ef"` split on `":"` with a `max`
+    /// of 2 is `["ab", "cd:ef"]`. A `max` of 0 means no limit.
+    fn split_by_chars_max(&self, separator_chars: Option<&str>, max: usize) -> Vec<&str>;
+    /// Splits the string into runs of chars of the same Unicode general category, so
+    /// `"foo200Bar"` becomes `["foo", "200", "B", "ar"]`. An empty string gives an empty `Vec`.
+    ///
+    /// Chars are classified as whole code points, whereas Java classifies the two halves of a
+    /// surrogate pair on their own.
+    fn split_by_character_type(&self) -> Vec<&str>;
+    /// Like [`split_by_character_type`](StringUtilsExt::split_by_character_type), but an
+    /// uppercase letter followed by lowercase ones starts a new token, as in camel case:
+    /// `"ASFRules"` becomes `["ASF", "Rules"]` and `"fooBar"` becomes `["foo", "Bar"]`.
+    fn split_by_character_type_and_camel_case(&self) -> Vec<&str>;
+    /// Splits the string at every occurrence of the whole `separator`, or at Java whitespace
+    /// like [`split_by_chars`](StringUtilsExt::split_by_chars) if it is `None` or empty.
+    ///
+    /// As in Commons Lang, adjacent and leading separators produce no empty tokens, but a
+    /// trailing separator leaves an empty last token: `"-a--b-"` split on `"-"` is
+    /// `["a", "b", ""]`. An empty string gives an empty `Vec`.
+    fn split_by_whole_separator(&self, separator: Option<&str>) -> Vec<&str>;
+    /// Like [`split_by_whole_separator`](StringUtilsExt::split_by_whole_separator), but returns
+    /// at most `max` tokens, the last holding the rest of the string. A `max` of 0 means no
+    /// limit.
+    fn split_by_whole_separator_max(&self, separator: Option<&str>, max: usize) -> Vec<&str>;
+    /// Like [`split_by_chars`](StringUtilsExt::split_by_chars), but every separator ends a
+    /// token, so adjacent, leading and trailing separators produce empty tokens:
+    /// `":a::b:"` split on `":"` is `["", "a", "", "b", ""]`. An empty string gives an empty
+    /// `Vec`.
+    fn split_preserve_all_tokens(&self, separator_chars: Option<&str>) -> Vec<&str>;
+    /// Like [`split_preserve_all_tokens`](StringUtilsExt::split_preserve_all_tokens), but
+    /// returns at most `max` tokens, the last holding the rest of the string. A `max` of 0 means
+    /// no limit.
+    fn split_preserve_all_tokens_max(&self, separator_chars: Option<&str>, max: usize)
+        -> Vec<&str>;
+    /// Tests whether the string starts with `prefix`, optionally ignoring case. An empty
+    /// `prefix` always matches.
     fn starts_with(&self, prefix: &str, ignore_case: bool) -> bool;
+    /// Tests whether the string starts with any of `prefixes`. An empty string matches nothing.
     fn starts_with_any(&self, prefixes: &[&str]) -> bool;
-    fn strip(&self) -> String;
-    fn strip_in(&self, strip_chars: &str) -> String;
-    fn strip_end(&self) -> String;
-    fn strip_end_in(&self, strip_chars: &str) -> String;
-    fn strip_start(&self) -> String;
-    fn strip_start_in(&self, strip_chars: &str) -> String;
-    fn substring(&self, range: Range<usize>) -> String;
-    fn substring_after(&self, separator: char) -> String;
-    fn substring_after_last(&self, separator: char) -> String;
-    fn substring_after_last_str(&self, separator: &str) -> String;
-    fn substring_after_str(&self, separator: &str) -> String;
-    fn substring_before(&self, separator: char) -> String;
-    fn substring_before_last(&self, separator: char) -> String;
-    fn substring_before_last_str(&self, separator: &str) -> String;
-    fn substring_before_str(&self, separator: &str) -> String;
-    fn substring_between(&self, open: &str, close: &str) -> String;
-    fn substring_between_tag(&self, tag: &str) -> String;
+    /// Removes Unicode `White_Space` chars from both ends, unlike [`trim`](StringUtilsExt::trim)
+    /// which only removes control chars and the ASCII space.
+    fn strip(&self) -> &str;
+    /// Removes any of `strip_chars` from both ends. An empty `strip_chars` strips nothing.
+    fn strip_in(&self, strip_chars: &str) -> &str;
+    /// Removes Unicode `White_Space` chars from the end.
+    fn strip_end(&self) -> &str;
+    fn strip_end_in(&self, strip_chars: &str) -> &str;
+    /// Removes Unicode `White_Space` chars from the start.
+    fn strip_start(&self) -> &str;
+    fn strip_start_in(&self, strip_chars: &str) -> &str;
+    /// Same as [`strip`](StringUtilsExt::strip), for parity with Commons Lang's `stripToEmpty`.
+    fn strip_to_empty(&self) -> &str;
+    /// Like [`strip`](StringUtilsExt::strip), but returns `None` if what's left is empty or
+    /// [blank](StringUtilsExt::is_blank).
+    fn strip_to_none(&self) -> Option<&str>;
+    /// Returns the chars in `range`. Bounds past the end are clamped and an empty or reversed
+    /// range gives an empty string, so this never panics.
+    fn substring<R>(&self, range: R) -> &str
+    where
+        R: RangeBounds<usize>;
+    /// Returns the text after the first `separator`, or an empty string if it is missing.
+    fn substring_after(&self, separator: char) -> &str;
+    fn substring_after_last(&self, separator: char) -> &str;
+    /// Returns the text after the last `separator`, or an empty string if it is missing or empty.
+    fn substring_after_last_str(&self, separator: &str) -> &str;
+    /// Returns the text after the first `separator`, or an empty string if it is missing. An empty
+    /// `separator` returns the whole string.
+    fn substring_after_str(&self, separator: &str) -> &str;
+    /// Returns the text before the first `separator`, or the whole string if it is missing.
+    fn substring_before(&self, separator: char) -> &str;
+    fn substring_before_last(&self, separator: char) -> &str;
+    /// Returns the text before the last `separator`, or the whole string if it is missing or
+    /// empty.
+    fn substring_before_last_str(&self, separator: &str) -> &str;
+    /// Returns the text before the first `separator`, or the whole string if it is missing. An
+    /// empty `separator` returns an empty string.
+    fn substring_before_str(&self, separator: &str) -> &str;
+    /// Returns the text between the first `open` and the next `close` after it, or `None` if
+    /// either is missing. An empty `open` or `close` matches immediately.
+    fn substring_between(&self, open: &str, close: &str) -> Option<&str>;
+    fn substring_between_tag(&self, tag: &str) -> Option<&str>;
+    /// Like [`substring_between`](StringUtilsExt::substring_between), but also returns `None`
+    /// if the text between is blank.
+    fn substring_between_to_none(&self, open: &str, close: &str) -> Option<&str>;
+    /// Converts uppercase and titlecase chars to lowercase and lowercase chars to uppercase,
+    /// like Commons Lang. Other chars are unchanged.
+    ///
+    /// Full case mappings are used, so the result may differ in length from the input:
+    /// `"ßA".swap_case()` is `"SSa"`. Swapping twice does not always restore the input.
     fn swap_case(&self) -> String;
-    fn trim(&self) -> String;
-    fn truncate(&self, max_width: usize) -> String;
-    fn truncate_with_offset(&self, offset: usize, max_width: usize) -> String;
-    fn uncapitalize(&self) -> String;
-    fn unwrap_from_char(&self, wrap_char: char) -> String;
-    fn unwrap_from_str(&self, wrap_str: &str) -> String;
+    /// Removes chars from `'\u{0}'` to `' '` from both ends, like Java's `String.trim`. Other
+    /// Unicode whitespace is kept; use [`strip`](StringUtilsExt::strip) to remove it.
+    fn trim(&self) -> &str;
+    /// Same as [`trim`](StringUtilsExt::trim), for parity with Commons Lang's `trimToEmpty`.
+    fn trim_to_empty(&self) -> &str;
+    /// Like [`trim`](StringUtilsExt::trim), but returns `None` if what's left is empty or
+    /// [blank](StringUtilsExt::is_blank).
+    fn trim_to_none(&self) -> Option<&str>;
+    /// Keeps at most the first `max_width` chars, without adding a marker.
+    fn truncate(&self, max_width: usize) -> &str;
+    /// Keeps at most `max_width` chars starting at char `offset`. An `offset` past the end
+    /// gives an empty string.
+    fn truncate_with_offset(&self, offset: usize, max_width: usize) -> &str;
+    /// Keeps the longest start of the string that fits in `max_width` display columns, as
+    /// measured by [`display_width`](StringUtilsExt::display_width). Grapheme clusters are never
+    /// split, and a wide char that would cross the limit is dropped whole.
+    fn truncate_to_width(&self, max_width: usize) -> &str;
+    /// Like [`truncate_to_width`](StringUtilsExt::truncate_to_width), but starts at the char
+    /// offset `offset`.
+    fn truncate_to_width_with_offset(&self, offset: usize, max_width: usize) -> &str;
+    /// Converts the first char to lowercase, leaving the rest unchanged.
+    ///
+    /// Full case mappings are used, so the result may have more chars than the input:
+    /// `"İx".uncapitalize()` is `"i\u{307}x"`.
+    fn uncapitalize(&self) -> Cow<'_, str>;
+    /// Removes `wrap_char` from both ends if it is at both ends. Returns the string unchanged
+    /// otherwise, or if it is a single char or `wrap_char` is `'\0'`.
+    fn unwrap_from_char(&self, wrap_char: char) -> &str;
+    /// Removes `wrap_str` from both ends if it is at both ends without overlapping. Returns the
+    /// string unchanged otherwise, or if `wrap_str` is empty.
+    fn unwrap_from_str(&self, wrap_str: &str) -> &str;
+    /// Removes a pair of `quote` chars wrapping the whole string and turns each doubled `quote`
+    /// inside into a single one, so `"'it''s'".unwrap_quoted('\'')` is `"it's"`.
+    ///
+    /// Returns the string unchanged unless it is exactly one quoted token: it must start and end
+    /// with `quote`, and every `quote` in between must be doubled.
+    fn unwrap_quoted(&self, quote: char) -> Cow<'_, str>;
+    /// Converts every char to uppercase using full case mappings, so the result may differ in
+    /// length from the input: `"straße".upper_case()` is `"STRASSE"`.
     fn upper_case(&self) -> String;
-    fn wrap(&self, wrap_with: char) -> String;
-    fn wrap_if_missing(&self, wrap_with: char) -> String;
-    fn wrap_if_missing_str(&self, wrap_with: &str) -> String;
-    fn wrap_with_str(&self, wrap_with: &str) -> String;
+    /// Puts `wrap_with` on both ends. An empty string, or `wrap_with` of `'\0'`, is returned
+    /// unchanged.
+    fn wrap(&self, wrap_with: char) -> Cow<'_, str>;
+    /// Adds `wrap_with` to each end that doesn't already have it. An empty string, or
+    /// `wrap_with` of `'\0'`, is returned unchanged.
+    fn wrap_if_missing(&self, wrap_with: char) -> Cow<'_, str>;
+    /// Adds `wrap_with` to each end that doesn't already have it. An empty string, or an empty
+    /// `wrap_with`, is returned unchanged.
+    fn wrap_if_missing_str(&self, wrap_with: &str) -> Cow<'_, str>;
+    /// Puts `wrap_with` on both ends. An empty string, or an empty `wrap_with`, is returned
+    /// unchanged.
+    fn wrap_with_str(&self, wrap_with: &str) -> Cow<'_, str>;
+}
+
+/// What the `*_to_width` padding methods of [`StringUtilsExt`] do when the next grapheme of the
+/// pad string is wider than the columns left to fill.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum PadPolicy {
+    /// Fills the columns left with spaces, so the result is exactly as wide as asked.
+    #[default]
+    FillWithSpace,
+    /// Adds the grapheme anyway, so each padded side may end up a column too wide.
+    Overflow,
 }
 
+/// Helpers over several strings at once, ported from the varargs methods of `StringUtils`.
+///
+/// Every helper accepts any iterable of string-like values. The macros such as
+/// [`is_any_blank!`](crate::is_any_blank) wrap them for call sites that list values inline.
 pub struct StringUtils;
 
 /// A String for a space character.
-const SPACE: &'static str = " ";
+const SPACE: &str = " ";
 
 /// The empty String {@code ""}.
-const EMPTY: &'static str = "";
+pub(crate) const EMPTY: &str = "";
 
 /// A String for linefeed LF ("\n").
-const LF: &'static str = "\n";
+const LF: &str = "\n";
 
 /// A String for carriage return CR ("\r").
-const CR: &'static str = "\r";
-
-/// Represents a failed index search.
-const INDEX_NOT_FOUND: usize = usize::MAX;
+const CR: &str = "\r";
 
 /// The maximum size to which the padding constant(s) can expand.
 const PAD_LIMIT: usize = 8192;
 
-#[allow(dead_code)]
-#[allow(unused)]
-impl StringUtils {
-    pub fn first_non_blank<'a>(values: &[&'a str]) -> &'a str {
-        todo!()
-    }
-
-    pub fn first_non_empty<'a>(values: &[&'a str]) -> &'a str {
-        todo!()
-    }
+/// Returns the byte offset of the char at `char_index`, or `s.len()` if it is out of range.
+fn byte_index(s: &str, char_index: usize) -> usize {
+    s.char_indices().nth(char_index).map_or(s.len(), |(i, _)| i)
+}
 
-    pub fn get_common_prefix(str: &[&str]) -> String {
-        todo!()
-    }
+/// Returns the char offset of the char boundary at `byte_index`.
+fn char_index(s: &str, byte_index: usize) -> usize {
+    s[..byte_index].chars().count()
+}
 
-    pub fn index_of_difference(values: &[&str]) -> Option<usize> {
-        todo!()
+/// Builds `pads` chars of padding by cycling through `pad_str`, which must not be empty.
+///
+/// A single pad char is pushed one at a time up to [`PAD_LIMIT`]. Longer paddings and multi-char
+/// pad strings are built in bulk from whole repetitions of `pad_str` plus a cut-short tail.
+fn padding(pads: usize, pad_str: &str) -> String {
+    let mut chars = pad_str.chars();
+    if let (Some(pad_char), None) = (chars.next(), chars.next()) {
+        if pads <= PAD_LIMIT {
+            return core::iter::repeat(pad_char).take(pads).collect();
+        }
     }
 
-    pub fn is_all_blank(values: &[&str]) -> bool {
-        todo!()
-    }
+    let pad_len = pad_str.chars().count();
+    let tail = &pad_str[..byte_index(pad_str, pads % pad_len)];
+    let mut padding = pad_str.repeat(pads / pad_len);
+    padding.reserve_exact(tail.len());
+    padding.push_str(tail);
+    padding
+}
 
-    pub fn is_all_empty(values: &[&str]) -> bool {
-        todo!()
+/// Builds `columns` display columns of padding by cycling through the grapheme clusters of
+/// `pad_str`, following `policy` when the next one is wider than the columns left. Pads with
+/// spaces if `pad_str` takes up no columns.
+fn padding_to_width(columns: usize, pad_str: &str, policy: PadPolicy) -> String {
+    if pad_str.display_width() == 0 {
+        return padding(columns, SPACE);
     }
-
-    pub fn is_all_lower_case(values: &[&str]) -> bool {
-        todo!()
+    if pad_str.chars().all(|c| c.width() == Some(1)) {
+        return padding(columns, pad_str);
     }
 
-    pub fn is_all_upper_case(values: &[&str]) -> bool {
-        todo!()
+    let mut padding = String::new();
+    let mut width = 0;
+    for grapheme in pad_str.graphemes(true).cycle() {
+        if width >= columns {
+            break;
+        }
+        let grapheme_width = grapheme.width();
+        if width + grapheme_width > columns && policy == PadPolicy::FillWithSpace {
+            padding.push_str(&SPACE.repeat(columns - width));
+            break;
+        }
+        padding.push_str(grapheme);
+        width += grapheme_width;
     }
+    padding
+}
 
-    pub fn is_any_blank(values: &[&str]) -> bool {
-        todo!()
+/// Returns `s` without `prefix`, comparing chars case-insensitively, or `None` if `prefix` is
+/// missing or empty.
+fn strip_prefix_ignore_case<'a>(s: &'a str, prefix: &str) -> Option<&'a str> {
+    if prefix.is_empty() || !starts_with_ignore_case(s, prefix) {
+        return None;
     }
+    Some(&s[byte_index(s, prefix.chars().count())..])
+}
 
-    pub fn is_any_empty(values: &[&str]) -> bool {
-        todo!()
+/// Returns `s` without `suffix`, comparing chars case-insensitively, or `None` if `suffix` is
+/// missing or empty.
+fn strip_suffix_ignore_case<'a>(s: &'a str, suffix: &str) -> Option<&'a str> {
+    let str_len = s.chars().count();
+    let suffix_len = suffix.chars().count();
+    if suffix.is_empty() || suffix_len > str_len {
+        return None;
     }
-
-    pub fn is_none_blank(values: &[&str]) -> bool {
-        todo!()
+    let end = byte_index(s, str_len - suffix_len);
+    if !starts_with_ignore_case(&s[end..], suffix) {
+        return None;
     }
+    Some(&s[..end])
+}
 
-    pub fn is_none_empty(values: &[&str]) -> bool {
-        todo!()
+/// Removes every occurrence of `remove` from `s`, comparing chars case-insensitively, or returns
+/// `None` if there is none.
+fn remove_all_ignore_case(s: &str, remove: &str) -> Option<String> {
+    if remove.is_empty() {
+        return None;
+    }
+    let remove_len = remove.chars().count();
+    let mut result = String::new();
+    let mut copied = 0;
+    let mut pos = 0;
+    while let Some(c) = s[pos..].chars().next() {
+        if starts_with_ignore_case(&s[pos..], remove) {
+            result.push_str(&s[copied..pos]);
+            pos += byte_index(&s[pos..], remove_len);
+            copied = pos;
+        } else {
+            pos += c.len_utf8();
+        }
     }
-
-    pub fn join<T>(array: &[&T], separator: char) -> String
-    where
-        T: Display,
-    {
-        todo!()
+    if copied == 0 {
+        return None;
     }
+    result.push_str(&s[copied..]);
+    Some(result)
+}
 
-    pub fn join_with_str<T>(array: &[&T], separator: &str) -> String
-    where
-        T: Display,
-    {
-        todo!()
+/// Checks that every search string passed to `replace_each` has a replacement.
+fn check_replace_lists(search_list: &[&str], replace_list: &[&str]) -> UtilsResult<()> {
+    if search_list.len() != replace_list.len() {
+        return Err(InvalidArgument(format!(
+            "Search and Replace array lengths don't match: {} vs {}",
+            search_list.len(),
+            replace_list.len()
+        )));
     }
+    Ok(())
+}
 
-    pub fn join_in_range<T>(
-        array: &[&T],
-        delimiter: char,
-        start_index: usize,
-        end_index: usize,
-    ) -> String
-    where
-        T: Display,
-    {
-        todo!()
-    }
+/// A search string replaced by [`replace_each_once`]: its index in the search list, its byte
+/// offset in the input and the byte range its replacement takes in the output.
+struct Replacement {
+    search: usize,
+    found_at: usize,
+    output: Range<usize>,
+}
 
-    pub fn join_in_range_with_str<T>(
-        array: &[&T],
-        separator: &str,
-        start_index: usize,
-        end_index: usize,
-    ) -> String
-    where
-        T: Display,
+/// Replaces the search strings found in `text` in a single pass, or returns `None` if none of
+/// them occurs. The lists must have the same length. Every replacement made is appended to
+/// `replacements`, in order, when given.
+fn replace_each_once(
+    text: &str,
+    search_list: &[&str],
+    replace_list: &[&str],
+    mut replacements: Option<&mut Vec<Replacement>>,
+) -> Option<String> {
+    let find_from = |search: &str, start: usize| {
+        if search.is_empty() {
+            None
+        } else {
+            text[start..].find(search).map(|pos| pos + start)
+        }
+    };
+    let mut next_match: Vec<Option<usize>> = search_list
+        .iter()
+        .map(|search| find_from(search, 0))
+        .collect();
+
+    let mut result = String::new();
+    let mut start = 0;
+    while let Some((pos, i)) = next_match
+        .iter()
+        .enumerate()
+        .filter_map(|(i, pos)| pos.map(|pos| (pos, i)))
+        .min()
     {
-        todo!()
-    }
-
-    pub fn strip_all(values: &[&str]) -> Vec<String> {
-        todo!()
+        result.push_str(&text[start..pos]);
+        if let Some(replacements) = replacements.as_mut() {
+            replacements.push(Replacement {
+                search: i,
+                found_at: pos,
+                output: result.len()..result.len() + replace_list[i].len(),
+            });
+        }
+        result.push_str(replace_list[i]);
+        start = pos + search_list[i].len();
+        for (j, pos) in next_match.iter_mut().enumerate() {
+            if matches!(*pos, Some(p) if p < start) {
+                *pos = find_from(search_list[j], start);
+            }
+        }
     }
-
-    pub fn strip_all_in(values: &[&str], strip_chars: &str) -> Vec<String> {
-        todo!()
+    if start == 0 {
+        return None;
     }
+    result.push_str(&text[start..]);
+    Some(result)
 }
 
-impl<'a> StringUtilsExt for &'a str {
-    fn abbreviate(&self, max_width: usize) -> UtilsResult<String> {
-        self.abbreviate_with_full_opt("...", 0, max_width)
-    }
-
-    fn abbreviate_with_abbrev_marker(
-        &self,
-        abbrev_marker: &str,
-        max_width: usize,
-    ) -> UtilsResult<String> {
-        self.abbreviate_with_full_opt(abbrev_marker, 0, max_width)
-    }
-
-    fn abbreviate_with_full_opt(
-        &self,
-        abbrev_marker: &str,
-        mut offset: isize,
-        max_width: usize,
-    ) -> UtilsResult<String> {
-        if self.is_not_empty() && abbrev_marker == EMPTY && max_width > 0 {
-            return Ok(self.substring(0..max_width));
+/// Finds a chain of replacements, starting from one of the search strings in `start`, whose
+/// output contains a search string seen earlier along the chain. Returns the search strings
+/// along the cycle, with the first one repeated at the end.
+fn find_replacement_cycle(
+    search_list: &[&str],
+    replace_list: &[&str],
+    start: impl IntoIterator<Item = usize>,
+) -> Option<Vec<String>> {
+    fn visit(
+        i: usize,
+        search_list: &[&str],
+        replace_list: &[&str],
+        path: &mut Vec<usize>,
+        done: &mut [bool],
+    ) -> Option<Vec<String>> {
+        path.push(i);
+        for (j, search) in search_list.iter().enumerate() {
+            if search.is_empty() || !replace_list[i].contains(search) {
+                continue;
+            }
+            if let Some(from) = path.iter().position(|&k| k == j) {
+                return Some(cycle_names(search_list, &path[from..]));
+            }
+            if !done[j] {
+                if let Some(cycle) = visit(j, search_list, replace_list, path, done) {
+                    return Some(cycle);
+                }
+            }
         }
-
-        if StringUtils::is_any_empty(&[self, abbrev_marker]) {
-            return Ok(String::from(*self));
-        }
-
-        let abbrev_marker_length = abbrev_marker.len();
-        let min_abbrev_width = abbrev_marker_length + 1;
-        let min_abbrev_width_offset = abbrev_marker_length + abbrev_marker_length + 1;
-
-        if max_width < min_abbrev_width {
-            return Err(InvalidArgument(format!(
-                "Minimum abbreviation width is {}",
-                min_abbrev_width
-            )));
+        path.pop();
+        done[i] = true;
+        None
+    }
+
+    let mut done = vec![false; search_list.len()];
+    let mut path = Vec::new();
+    for i in start {
+        if !done[i] {
+            if let Some(cycle) = visit(i, search_list, replace_list, &mut path, &mut done) {
+                return Some(cycle);
+            }
         }
+    }
+    None
+}
 
-        let str_len = self.len() as isize;
-        if str_len <= max_width as isize {
-            return Ok(String::from(*self));
-        }
-        if offset > str_len {
-            offset = str_len;
+/// Finds a path from `from` to `to` in `graph`, given as the successors of each node. Returns the
+/// nodes along it, both ends included.
+fn find_path(graph: &[Vec<usize>], from: usize, to: usize) -> Option<Vec<usize>> {
+    fn visit(graph: &[Vec<usize>], to: usize, path: &mut Vec<usize>, seen: &mut [bool]) -> bool {
+        let node = path[path.len() - 1];
+        if node == to {
+            return true;
         }
-        if str_len - offset < (max_width - abbrev_marker_length) as isize {
-            offset = str_len - (max_width - abbrev_marker_length) as isize
-        }
-        if offset <= (abbrev_marker_length + 1) as isize {
-            return Ok(self.substring(0..(max_width - abbrev_marker_length)) + abbrev_marker);
-        }
-        if max_width < min_abbrev_width_offset {
-            return Err(InvalidArgument(format!(
-                "Minimum abbreviation width with offset is {}",
-                min_abbrev_width_offset
-            )));
+        seen[node] = true;
+        for &next in &graph[node] {
+            if !seen[next] {
+                path.push(next);
+                if visit(graph, to, path, seen) {
+                    return true;
+                }
+                path.pop();
+            }
         }
-        if (offset + (max_width - abbrev_marker_length) as isize) < str_len {
-            return Ok(String::from(abbrev_marker)
-                + &self
-                    .substring(offset as usize..self.len())
-                    .as_str()
-                    .abbreviate_with_full_opt(
-                        abbrev_marker,
-                        0,
-                        max_width - abbrev_marker_length,
-                    )?);
-        }
-
-        return Ok(String::from(abbrev_marker)
-            + &self.substring(
-                (str_len - (max_width - abbrev_marker_length) as isize) as usize..self.len(),
-            ));
-    }
-
-    fn abbreviate_middle(&self, middle: &str, length: usize) -> UtilsResult<String> {
-        todo!()
-    }
-
-    fn abbreviate_with_offset(&self, offset: isize, max_width: usize) -> UtilsResult<String> {
-        todo!()
-    }
-
-    fn append_if_missing(&self, suffix: &str, ignore_case: bool) -> String {
-        todo!()
-    }
-
-    fn append_if_missing_one_of(
-        &self,
-        suffix: &str,
-        suffixes: &[&str],
-        ignore_case: bool,
-    ) -> String {
-        todo!()
-    }
-
-    fn capitalize(&self) -> String {
-        todo!()
-    }
-
-    fn center(&self, size: usize) -> String {
-        todo!()
-    }
-
-    fn center_with(&self, size: usize, pad_str: &str) -> String {
-        todo!()
-    }
-
-    fn center_with_char(&self, size: usize, pad_char: &str) -> String {
-        todo!()
-    }
-
-    fn chomp(&self) -> String {
-        todo!()
-    }
-
-    fn chomp_specified(&self, separator: &str) -> String {
-        todo!()
+        false
     }
 
-    fn chop(&self) -> String {
-        todo!()
-    }
-
-    fn compare(&self, str2: &str) -> i32 {
-        todo!()
-    }
-
-    fn compare_ignore_case(&self, str2: &str) -> i32 {
-        todo!()
-    }
-
-    fn contains(&self, search: &str) -> bool {
-        todo!()
-    }
-
-    fn contains_char(&self, search_char: char) -> bool {
-        todo!()
-    }
-
-    fn contains_any(&self, searches: &[&str]) -> bool {
-        todo!()
-    }
-
-    fn contains_any_char(&self, searches: &[char]) -> bool {
-        todo!()
-    }
-
-    fn contains_any_in(&self, searches_str: &str) -> bool {
-        todo!()
-    }
-
-    fn contains_any_ignore_case(&self, searches: &[&str]) -> bool {
-        todo!()
-    }
-
-    fn contains_any_with<T>(&self, searches: &[&str], test: T) -> bool
-    where
-        T: Fn(&str, &str) -> bool,
-    {
-        todo!()
-    }
-
-    fn contains_ignore_case(&self, search: &str) -> bool {
-        todo!()
-    }
+    let mut path = vec![from];
+    let mut seen = vec![false; graph.len()];
+    visit(graph, to, &mut path, &mut seen).then_some(path)
+}
 
-    fn contains_none(&self, searches: &[char]) -> bool {
-        todo!()
-    }
+/// Names the search strings at `indices`, repeating the first one at the end to close the cycle.
+fn cycle_names(search_list: &[&str], indices: &[usize]) -> Vec<String> {
+    indices
+        .iter()
+        .chain(indices.first())
+        .map(|&i| String::from(search_list[i]))
+        .collect()
+}
 
-    fn contains_none_in(&self, invalid_chars: &str) -> bool {
-        todo!()
-    }
+/// Tests whether `c` is a letter, like Java's `Character.isLetter`.
+fn is_letter(c: char) -> bool {
+    matches!(
+        get_general_category(c),
+        GeneralCategory::UppercaseLetter
+            | GeneralCategory::LowercaseLetter
+            | GeneralCategory::TitlecaseLetter
+            | GeneralCategory::ModifierLetter
+            | GeneralCategory::OtherLetter
+    )
+}
 
-    fn contains_only(&self, valid: &[char]) -> bool {
-        todo!()
-    }
+/// Tests whether `c` is a decimal digit in any script, like Java's `Character.isDigit`.
+fn is_digit(c: char) -> bool {
+    get_general_category(c) == GeneralCategory::DecimalNumber
+}
 
-    fn contains_only_in(&self, valid_chars: &str) -> bool {
-        todo!()
+/// Tests whether `c` is whitespace like Java's `Character.isWhitespace`: a space, line or
+/// paragraph separator other than the no-break `'\u{A0}'`, `'\u{2007}'` and `'\u{202F}'`, or
+/// one of `'\t'..='\r'` and `'\u{1C}'..='\u{1F}'`.
+fn is_java_whitespace(c: char) -> bool {
+    match c {
+        '\t'..='\r' | '\u{1C}'..='\u{1F}' => true,
+        '\u{A0}' | '\u{2007}' | '\u{202F}' => false,
+        _ => matches!(
+            get_general_category(c),
+            GeneralCategory::SpaceSeparator
+                | GeneralCategory::LineSeparator
+                | GeneralCategory::ParagraphSeparator
+        ),
     }
+}
 
-    fn contains_whitespace(&self) -> bool {
-        todo!()
-    }
+/// Compares two chars the way Java's `String.regionMatches(true, ...)` does.
+fn char_eq_ignore_case(a: char, b: char) -> bool {
+    a == b || a.to_uppercase().eq(b.to_uppercase()) || a.to_lowercase().eq(b.to_lowercase())
+}
 
-    fn count_matches(&self, ch: char) -> u64 {
-        todo!()
-    }
+/// Tests whether `s` starts with `prefix`, ignoring case char by char.
+fn starts_with_ignore_case(s: &str, prefix: &str) -> bool {
+    let mut chars = s.chars();
+    prefix
+        .chars()
+        .all(|p| chars.next().is_some_and(|c| char_eq_ignore_case(c, p)))
+}
 
-    fn count_matches_str(&self, sub: &str) -> u64 {
-        todo!()
-    }
+/// Compiles `regex`, letting `.` match `'\n'` if `dot_matches_new_line` is set (Java's
+/// `DOTALL`).
+#[cfg(feature = "regex")]
+fn compile_regex(regex: &str, dot_matches_new_line: bool) -> UtilsResult<Regex> {
+    Ok(RegexBuilder::new(regex)
+        .dot_matches_new_line(dot_matches_new_line)
+        .build()?)
+}
 
-    fn default_if_blank(&self, default_str: &str) -> String {
-        todo!()
-    }
+/// Maps `c` to its simple case folding, used to compare strings ignoring case.
+fn fold_case(c: char) -> char {
+    SIMPLE_CASE_FOLDING
+        .binary_search_by_key(&c, |&(from, _)| from)
+        .map_or(c, |i| SIMPLE_CASE_FOLDING[i].1)
+}
 
-    fn default_if_empty(&self, default_str: &str) -> String {
-        todo!()
+/// Orders `None` relative to `Some` as `nulls_first` asks, and compares two `Some` with
+/// `compare`.
+fn compare_options<S>(
+    str1: Option<S>,
+    str2: Option<S>,
+    nulls_first: bool,
+    compare: impl FnOnce(&str, &str) -> Ordering,
+) -> Ordering
+where
+    S: AsRef<str>,
+{
+    match (str1, str2) {
+        (Some(str1), Some(str2)) => compare(str1.as_ref(), str2.as_ref()),
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) if nulls_first => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (Some(_), None) if nulls_first => Ordering::Greater,
+        (Some(_), None) => Ordering::Less,
     }
+}
 
-    fn default_string(&self) -> String {
-        todo!()
+/// Moves the last `shift` segments to the front, or the first `-shift` to the back.
+fn rotate_segments(segments: &[&str], shift: isize) -> String {
+    if segments.is_empty() {
+        return String::new();
     }
+    let split = segments.len() - shift.rem_euclid(segments.len() as isize) as usize;
+    segments[split..].concat() + &segments[..split].concat()
+}
 
-    fn delete_whitespace(&self) -> String {
-        todo!()
-    }
+/// Joins the non-empty `fields` in reverse order with `separator`.
+fn join_reversed(fields: Vec<&str>, separator: &str) -> String {
+    let mut fields = fields;
+    fields.retain(|field| !field.is_empty());
+    fields.reverse();
+    fields.join(separator)
+}
 
-    fn difference(&self, str2: &str) -> String {
-        todo!()
+/// Splits `s` at `separator_chars`, or at Java whitespace if `None`, into at most `max` tokens (0
+/// for no limit). Ported from Commons Lang's `splitWorker`.
+fn split_worker<'a>(
+    s: &'a str,
+    separator_chars: Option<&str>,
+    max: usize,
+    preserve_all_tokens: bool,
+) -> Vec<&'a str> {
+    let is_separator = |c: char| match separator_chars {
+        Some(separator_chars) => separator_chars.contains(c),
+        None => is_java_whitespace(c),
+    };
+    let mut tokens = Vec::new();
+    let mut start = 0;
+    let mut in_token = false;
+    let mut last_was_separator = false;
+    for (i, c) in s.char_indices() {
+        if !is_separator(c) {
+            in_token = true;
+            last_was_separator = false;
+            continue;
+        }
+        if in_token || preserve_all_tokens {
+            if tokens.len() + 1 == max {
+                tokens.push(&s[start..]);
+                return tokens;
+            }
+            tokens.push(&s[start..i]);
+            in_token = false;
+            last_was_separator = true;
+        }
+        start = i + c.len_utf8();
     }
-
-    fn digits(&self) -> String {
-        todo!()
+    if in_token || (preserve_all_tokens && last_was_separator) {
+        tokens.push(&s[start..]);
     }
+    tokens
+}
 
-    fn end_with(&self, suffix: &str, ignore_case: bool) -> bool {
-        todo!()
+/// Splits `s` at each whole `separator` into at most `max` tokens (0 for no limit). Ported
+/// from Commons Lang's `splitByWholeSeparatorWorker`.
+fn split_by_whole_separator_worker<'a>(
+    s: &'a str,
+    separator: Option<&str>,
+    max: usize,
+) -> Vec<&'a str> {
+    let separator = match separator {
+        Some(separator) if !separator.is_empty() => separator,
+        _ => return split_worker(s, None, max, false),
+    };
+    let mut tokens = Vec::new();
+    if s.is_empty() {
+        return tokens;
+    }
+    let mut begin = 0;
+    while let Some(offset) = s[begin..].find(separator) {
+        let end = begin + offset;
+        if end > begin {
+            if tokens.len() + 1 == max {
+                break;
+            }
+            tokens.push(&s[begin..end]);
+        }
+        begin = end + separator.len();
     }
+    tokens.push(&s[begin..]);
+    tokens
+}
 
-    fn end_with_any(&self, searches: &[&str]) -> bool {
-        todo!()
+/// Splits `s` wherever the general category changes. With `camel_case`, the last uppercase
+/// letter of a run goes with the lowercase run that follows it.
+fn split_by_character_type(s: &str, camel_case: bool) -> Vec<&str> {
+    let mut chars = s.char_indices();
+    let mut current_type = match chars.next() {
+        Some((_, c)) => get_general_category(c),
+        None => return Vec::new(),
+    };
+    let mut tokens = Vec::new();
+    let mut token_start = 0;
+    let mut last_start = 0;
+    for (pos, c) in chars {
+        let char_type = get_general_category(c);
+        if char_type != current_type {
+            if camel_case
+                && char_type == GeneralCategory::LowercaseLetter
+                && current_type == GeneralCategory::UppercaseLetter
+            {
+                if last_start != token_start {
+                    tokens.push(&s[token_start..last_start]);
+                    token_start = last_start;
+                }
+            } else {
+                tokens.push(&s[token_start..pos]);
+                token_start = pos;
+            }
+            current_type = char_type;
+        }
+        last_start = pos;
     }
+    tokens.push(&s[token_start..]);
+    tokens
+}
 
-    fn end_with_consider_case(&self, suffix: &str) -> bool {
-        todo!()
-    }
+/// Maps `c` to its full titlecase form, which may be several chars (`'ß'` becomes `"Ss"`).
+fn to_titlecase(c: char) -> impl Iterator<Item = char> {
+    let exception = TITLECASE_EXCEPTIONS
+        .binary_search_by_key(&c, |&(from, _)| from)
+        .ok()
+        .map(|i| TITLECASE_EXCEPTIONS[i].1);
+    let upper = c.to_uppercase().filter(move |_| exception.is_none());
+    exception.into_iter().flat_map(str::chars).chain(upper)
+}
 
-    fn end_with_ignore_case(&self, suffix: &str) -> bool {
-        todo!()
+/// Rebuilds `s` with its first char replaced by the chars of `map(first)`, borrowing `s` if
+/// that changes nothing.
+fn map_first_char<I>(s: &str, map: impl Fn(char) -> I) -> Cow<'_, str>
+where
+    I: Iterator<Item = char>,
+{
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(first) if !map(first).eq(core::iter::once(first)) => {
+            Cow::Owned(map(first).chain(chars).collect())
+        }
+        _ => Cow::Borrowed(s),
     }
+}
 
-    fn equals(&self, str2: &str) -> bool {
-        todo!()
+/// Returns the longest end of `s` that fits in `max_width` display columns, without splitting a
+/// grapheme cluster.
+fn last_to_width(s: &str, max_width: usize) -> &str {
+    let mut width = 0;
+    for (start, grapheme) in s.grapheme_indices(true).rev() {
+        width += grapheme.width();
+        if width > max_width {
+            return &s[start + grapheme.len()..];
+        }
     }
+    s
+}
 
-    fn equals_any(&self, searches: &[&str]) -> bool {
-        todo!()
+/// Tests whether `normalize_space` would leave `s` unchanged: it has no whitespace at either
+/// end, and its only whitespace is single `' '` between words.
+fn is_space_normalized(s: &str) -> bool {
+    let mut after_space = true;
+    for c in s.chars() {
+        if c.is_whitespace() {
+            if c != ' ' || after_space {
+                return false;
+            }
+            after_space = true;
+        } else {
+            after_space = false;
+        }
     }
+    !after_space || s.is_empty()
+}
 
-    fn equals_any_ignore_case(&self, searches: &[&str]) -> bool {
-        todo!()
-    }
+/// Sums the display widths of the grapheme clusters of `s` that start before char `offset`.
+fn width_before(s: &str, offset: usize) -> usize {
+    let mut chars = 0;
+    s.graphemes(true)
+        .take_while(|grapheme| {
+            let starts_before = chars < offset;
+            chars += grapheme.chars().count();
+            starts_before
+        })
+        .map(UnicodeWidthStr::width)
+        .sum()
+}
 
-    fn equals_ignore_case(&self, str2: &str) -> bool {
-        todo!()
+/// Skips the grapheme clusters of `s` that end within the first `columns` display columns.
+fn skip_width(s: &str, columns: usize) -> &str {
+    let mut width = 0;
+    for (start, grapheme) in s.grapheme_indices(true) {
+        width += grapheme.width();
+        if width > columns {
+            return &s[start..];
+        }
     }
+    EMPTY
+}
 
-    fn if_blank<T>(&self, default_supplier: T) -> String
+impl StringUtils {
+    /// Compares two optional strings like [`StringUtilsExt::compare`], putting `None` before
+    /// every string if `nulls_first` is set and after every string otherwise. Two `None` are
+    /// equal.
+    pub fn compare_nullable(str1: Option<&str>, str2: Option<&str>, nulls_first: bool) -> Ordering {
+        compare_options(str1, str2, nulls_first, |str1, str2| str1.compare(str2))
+    }
+
+    /// Compares two optional strings like [`StringUtilsExt::compare_ignore_case`], putting
+    /// `None` before every string if `nulls_first` is set and after every string otherwise.
+    pub fn compare_ignore_case_nullable(
+        str1: Option<&str>,
+        str2: Option<&str>,
+        nulls_first: bool,
+    ) -> Ordering {
+        compare_options(str1, str2, nulls_first, |str1, str2| {
+            str1.compare_ignore_case(str2)
+        })
+    }
+
+    /// Compares two optional strings with `None` first. Fits `sort_by` directly:
+    /// `names.sort_by(StringUtils::compare_nulls_first)`.
+    pub fn compare_nulls_first<S>(str1: &Option<S>, str2: &Option<S>) -> Ordering
     where
-        T: Fn() -> String,
+        S: AsRef<str>,
     {
-        todo!()
+        compare_options(str1.as_ref(), str2.as_ref(), true, |str1, str2| {
+            str1.compare(str2)
+        })
     }
 
-    fn if_empty<T>(&self, default_supplier: T) -> String
+    /// Compares two optional strings with `None` last. Fits `sort_by` directly.
+    pub fn compare_nulls_last<S>(str1: &Option<S>, str2: &Option<S>) -> Ordering
     where
-        T: Fn() -> String,
+        S: AsRef<str>,
     {
-        todo!()
-    }
-
-    fn index_of(&self, search: &str) -> Option<usize> {
-        todo!()
-    }
-
-    fn index_of_any(&self, searches: &[&str]) -> Option<usize> {
-        todo!()
-    }
-
-    fn index_of_any_but(&self, search_chars: &[char]) -> Option<usize> {
-        todo!()
-    }
-
-    fn index_of_any_but_in(&self, search_chars: &str) -> Option<usize> {
-        todo!()
-    }
-
-    fn index_of_any_char(&self, search_chars: &[char]) -> Option<usize> {
-        todo!()
-    }
-
-    fn index_of_any_in(&self, search_chars: &str) -> Option<usize> {
-        todo!()
+        compare_options(str1.as_ref(), str2.as_ref(), false, |str1, str2| {
+            str1.compare(str2)
+        })
     }
 
-    fn index_of_char(&self, search_char: char) -> Option<usize> {
-        todo!()
-    }
-
-    fn index_of_char_starting_from(&self, search_char: char, start_pos: usize) -> Option<usize> {
-        todo!()
-    }
-
-    fn index_of_starting_from(&self, search: &str, start_pos: usize) -> Option<usize> {
-        todo!()
-    }
-
-    fn index_of_difference(&self, str2: &str) -> Option<usize> {
-        todo!()
-    }
-
-    fn index_of_ignore_case(&self, search: &str) -> Option<usize> {
-        todo!()
-    }
-
-    fn index_of_ignore_case_starting_from(&self, search: &str, start_pos: usize) -> Option<usize> {
-        todo!()
-    }
-
-    fn is_alpha(&self) -> bool {
-        todo!()
-    }
-
-    fn is_alphanumeric(&self) -> bool {
-        todo!()
-    }
-
-    fn is_alphanumeric_space(&self) -> bool {
-        todo!()
-    }
-
-    fn is_alpha_space(&self) -> bool {
-        todo!()
-    }
-
-    fn is_ascii_printable(&self) -> bool {
-        todo!()
-    }
-
-    fn is_blank(&self) -> bool {
-        todo!()
-    }
-
-    fn is_empty(&self) -> bool {
-        todo!()
-    }
-
-    fn is_mixed_case(&self) -> bool {
-        todo!()
-    }
-
-    fn is_not_blank(&self) -> bool {
-        todo!()
-    }
-
-    fn is_not_empty(&self) -> bool {
-        todo!()
-    }
-
-    fn is_numeric(&self) -> bool {
-        todo!()
-    }
-
-    fn is_numeric_space(&self) -> bool {
-        todo!()
-    }
-
-    fn is_whitespace(&self) -> bool {
-        todo!()
-    }
-
-    fn last_index_of(&self, search: &str) -> Option<usize> {
-        todo!()
-    }
-
-    fn last_index_of_any(&self, searches: &[&str]) -> Option<usize> {
-        todo!()
-    }
-
-    fn last_index_of_char(&self, search_char: char) -> Option<usize> {
-        todo!()
-    }
-
-    fn last_index_of_char_starting_from(
-        &self,
-        search_char: char,
-        start_pos: usize,
-    ) -> Option<usize> {
-        todo!()
-    }
-
-    fn last_index_of_ignore_case(&self, search: &str) -> Option<usize> {
-        todo!()
-    }
-
-    fn last_index_of_ignore_case_starting_from(
-        &self,
-        search: &str,
-        start_pos: usize,
-    ) -> Option<usize> {
-        todo!()
-    }
-
-    fn last_index_of_starting_from(&self, search: &str, start_pos: usize) -> Option<usize> {
-        todo!()
-    }
-
-    fn last_ordinal_index_of(&self, search: &str, ordinal: usize) -> Option<usize> {
-        todo!()
-    }
-
-    fn left(&self, len: usize) -> String {
-        todo!()
-    }
-
-    fn left_pad(&self, size: usize) -> String {
-        todo!()
-    }
-
-    fn left_pad_with(&self, size: usize, pad_char: char) -> String {
-        todo!()
-    }
-
-    fn left_pad_with_str(&self, size: usize, pad_str: &str) -> String {
-        todo!()
-    }
-
-    fn lower_case(&self) -> String {
-        todo!()
+    /// Compares two optional strings ignoring case, with `None` first. Fits `sort_by` directly.
+    pub fn compare_ignore_case_nulls_first<S>(str1: &Option<S>, str2: &Option<S>) -> Ordering
+    where
+        S: AsRef<str>,
+    {
+        compare_options(str1.as_ref(), str2.as_ref(), true, |str1, str2| {
+            str1.compare_ignore_case(str2)
+        })
     }
 
-    fn mid(&self, pos: usize, len: usize) -> String {
-        todo!()
+    /// Compares two optional strings ignoring case, with `None` last. Fits `sort_by` directly.
+    pub fn compare_ignore_case_nulls_last<S>(str1: &Option<S>, str2: &Option<S>) -> Ordering
+    where
+        S: AsRef<str>,
+    {
+        compare_options(str1.as_ref(), str2.as_ref(), false, |str1, str2| {
+            str1.compare_ignore_case(str2)
+        })
     }
 
-    fn normalize_space(&self) -> String {
-        todo!()
+    /// Returns the first value that is not blank, or `None` if there is none.
+    pub fn first_non_blank<I, S>(values: I) -> Option<S>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        values
+            .into_iter()
+            .find(|value| value.as_ref().is_not_blank())
     }
 
-    fn ordinal_index_of(&self, search: &str, ordinal: usize) -> Option<usize> {
-        todo!()
+    /// Returns the first value that is not empty, or `None` if there is none.
+    pub fn first_non_empty<I, S>(values: I) -> Option<S>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        values.into_iter().find(|value| !value.as_ref().is_empty())
     }
 
-    fn overlay(&self, overlay: &str, start: usize, end: usize) -> String {
-        todo!()
+    /// Returns the longest prefix shared by all values, or an empty string if there are none.
+    pub fn get_common_prefix<I, S>(values: I) -> String
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let values: Vec<S> = values.into_iter().collect();
+        let first = match values.first() {
+            Some(first) => first.as_ref(),
+            None => return String::new(),
+        };
+        match StringUtils::index_of_difference(&values) {
+            Some(pos) => String::from(first.substring(..pos)),
+            None => String::from(first),
+        }
     }
 
-    fn prepend_if_missing(&self, prefix: &str, ignore_case: bool) -> String {
-        todo!()
+    /// Returns the char offset at which the values start to differ, or `None` if they are all
+    /// equal or there are fewer than two.
+    pub fn index_of_difference<I, S>(values: I) -> Option<usize>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut values = values.into_iter();
+        let first = values.next()?;
+        values
+            .filter_map(|value| first.as_ref().index_of_difference(value.as_ref()))
+            .min()
     }
 
-    fn prepend_if_missing_one_of(
-        &self,
-        prefix: &str,
-        prefixes: &[&str],
-        ignore_case: bool,
-    ) -> String {
-        todo!()
+    /// Tests whether every value is blank. Passes when there are no values.
+    pub fn is_all_blank<I, S>(values: I) -> bool
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        values.into_iter().all(|value| value.as_ref().is_blank())
     }
 
-    fn remove(&self, remove: &str) -> String {
-        todo!()
+    /// Tests whether every value is empty. Passes when there are no values.
+    pub fn is_all_empty<I, S>(values: I) -> bool
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        values.into_iter().all(|value| value.as_ref().is_empty())
     }
 
-    fn remove_char(&self, remove: char) -> String {
-        todo!()
+    /// Tests whether every value is non-empty and only contains lowercase chars.
+    pub fn is_all_lower_case<I, S>(values: I) -> bool
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        values.into_iter().all(|value| {
+            let value = value.as_ref();
+            !value.is_empty() && value.chars().all(char::is_lowercase)
+        })
     }
 
-    fn remove_all(&self, remove: &str) -> String {
-        todo!()
+    /// Tests whether every value is non-empty and only contains uppercase chars.
+    pub fn is_all_upper_case<I, S>(values: I) -> bool
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        values.into_iter().all(|value| {
+            let value = value.as_ref();
+            !value.is_empty() && value.chars().all(char::is_uppercase)
+        })
     }
 
-    fn remove_end(&self, remove: &str) -> String {
-        todo!()
+    /// Tests whether any value is blank. Fails when there are no values.
+    pub fn is_any_blank<I, S>(values: I) -> bool
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        values.into_iter().any(|value| value.as_ref().is_blank())
     }
 
-    fn remove_end_ignore_case(&self, remove: &str) -> String {
-        todo!()
+    /// Tests whether any value is empty. Fails when there are no values.
+    pub fn is_any_empty<I, S>(values: I) -> bool
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        values.into_iter().any(|value| value.as_ref().is_empty())
     }
 
-    fn remove_first(&self, remove: &str) -> String {
-        todo!()
+    /// Tests whether no value is blank. Passes when there are no values.
+    pub fn is_none_blank<I, S>(values: I) -> bool
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        !StringUtils::is_any_blank(values)
     }
 
-    fn remove_ignore_case(&self, remove: &str) -> String {
-        todo!()
+    /// Tests whether no value is empty. Passes when there are no values.
+    pub fn is_none_empty<I, S>(values: I) -> bool
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        !StringUtils::is_any_empty(values)
     }
 
-    fn remove_start(&self, remove: &str) -> String {
-        todo!()
+    /// Joins the values with `separator`.
+    pub fn join<I>(values: I, separator: char) -> String
+    where
+        I: IntoIterator,
+        I::Item: Display,
+    {
+        StringUtils::join_with_str(values, separator.encode_utf8(&mut [0; 4]))
     }
 
-    fn remove_start_char(&self, remove: char) -> String {
-        todo!()
+    /// Joins the values with `separator`.
+    pub fn join_with_str<I>(values: I, separator: &str) -> String
+    where
+        I: IntoIterator,
+        I::Item: Display,
+    {
+        let mut joiner = StringJoiner::new(separator);
+        joiner.extend(values);
+        joiner.to_string()
     }
 
-    fn remove_start_ignore_case(&self, remove: &str) -> String {
-        todo!()
+    /// Joins the `Some` values with `separator`, skipping every `None` along with its separator.
+    pub fn join_skip_none<I, T>(values: I, separator: &str) -> String
+    where
+        I: IntoIterator<Item = Option<T>>,
+        T: Display,
+    {
+        StringUtils::join_with_str(values.into_iter().flatten(), separator)
     }
 
-    fn repeat(&self, repeat: usize) -> String {
-        todo!()
+    /// Joins the values at positions `start_index..end_index` with `delimiter`.
+    ///
+    /// Returns [`InvalidArgument`](crate::error::UtilsError::InvalidArgument) if
+    /// `start_index > end_index` or if there are fewer than `end_index` values.
+    pub fn join_in_range<I>(
+        values: I,
+        delimiter: char,
+        start_index: usize,
+        end_index: usize,
+    ) -> UtilsResult<String>
+    where
+        I: IntoIterator,
+        I::Item: Display,
+    {
+        StringUtils::join_in_range_with_str(
+            values,
+            delimiter.encode_utf8(&mut [0; 4]),
+            start_index,
+            end_index,
+        )
+    }
+
+    /// Joins the values at positions `start_index..end_index` with `separator`.
+    ///
+    /// Returns [`InvalidArgument`](crate::error::UtilsError::InvalidArgument) if
+    /// `start_index > end_index` or if there are fewer than `end_index` values.
+    pub fn join_in_range_with_str<I>(
+        values: I,
+        separator: &str,
+        start_index: usize,
+        end_index: usize,
+    ) -> UtilsResult<String>
+    where
+        I: IntoIterator,
+        I::Item: Display,
+    {
+        if start_index > end_index {
+            return Err(InvalidArgument(format!(
+                "Start index {} is greater than end index {}",
+                start_index, end_index
+            )));
+        }
+        let mut joiner = StringJoiner::new(separator);
+        let mut len = start_index;
+        for value in values
+            .into_iter()
+            .skip(start_index)
+            .take(end_index - start_index)
+        {
+            joiner.add(value);
+            len += 1;
+        }
+        if len < end_index {
+            return Err(InvalidArgument(format!(
+                "End index {} is out of bounds for length {}",
+                end_index, len
+            )));
+        }
+        Ok(joiner.to_string())
     }
 
-    fn replace_chars(&self, search: char, replace: char) -> String {
-        todo!()
+    /// Strips Unicode whitespace from both ends of every value.
+    pub fn strip_all<I, S>(values: I) -> Vec<String>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        values
+            .into_iter()
+            .map(|value| String::from(value.as_ref().strip()))
+            .collect()
     }
 
-    fn replace_chars_in(&self, search_chars: &str, replace_chars: &str) -> String {
-        todo!()
+    /// Strips any of `strip_chars` from both ends of every value.
+    pub fn strip_all_in<I, S>(values: I, strip_chars: &str) -> Vec<String>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        values
+            .into_iter()
+            .map(|value| String::from(value.as_ref().strip_in(strip_chars)))
+            .collect()
     }
+}
 
-    fn replace_each(&self, search_list: &[&str], replace_list: &[&str]) -> UtilsResult<String> {
-        todo!()
+impl<S> StringUtilsExt for S
+where
+    S: AsRef<str> + ?Sized,
+{
+    fn abbreviate(&self, max_width: usize) -> UtilsResult<Cow<'_, str>> {
+        self.abbreviate_with_full_opt("...", 0, max_width)
     }
 
-    fn replace_each_repeatedly(
+    fn abbreviate_with_abbrev_marker(
         &self,
-        search_list: &[&str],
-        replace_list: &[&str],
-        time_to_live: Option<usize>,
-    ) -> UtilsResult<String> {
-        todo!()
-    }
-
-    fn replace_first(&self, search: &str, replace: &str) -> String {
-        todo!()
-    }
-
-    fn reverse(&self) -> String {
-        todo!()
-    }
-
-    fn reverse_delimited(&self, separator_char: char) -> String {
-        todo!()
-    }
-
-    fn right(&self, len: usize) -> String {
-        todo!()
-    }
-
-    fn right_pad(&self, size: usize) -> String {
-        todo!()
-    }
-
-    fn right_pad_with(&self, size: usize, pad_char: char) -> String {
-        todo!()
-    }
-
-    fn right_pad_with_str(&self, size: usize, pad_str: &str) -> String {
-        todo!()
-    }
-
-    fn rotate(&self, shift: isize) -> String {
-        todo!()
-    }
-
-    fn starts_with(&self, prefix: &str, ignore_case: bool) -> bool {
-        todo!()
-    }
-
-    fn starts_with_any(&self, prefixes: &[&str]) -> bool {
-        todo!()
-    }
-
-    fn strip(&self) -> String {
-        todo!()
-    }
-
-    fn strip_in(&self, strip_chars: &str) -> String {
-        todo!()
-    }
-
-    fn strip_end(&self) -> String {
-        todo!()
-    }
-
-    fn strip_end_in(&self, strip_chars: &str) -> String {
-        todo!()
-    }
-
-    fn strip_start(&self) -> String {
-        todo!()
-    }
-
-    fn strip_start_in(&self, strip_chars: &str) -> String {
-        todo!()
-    }
-
-    fn substring(&self, range: Range<usize>) -> String {
-        todo!()
-    }
-
-    fn substring_after(&self, separator: char) -> String {
-        todo!()
-    }
-
-    fn substring_after_last(&self, separator: char) -> String {
-        todo!()
-    }
-
-    fn substring_after_last_str(&self, separator: &str) -> String {
-        todo!()
-    }
-
-    fn substring_after_str(&self, separator: &str) -> String {
-        todo!()
-    }
-
-    fn substring_before(&self, separator: char) -> String {
-        todo!()
-    }
-
-    fn substring_before_last(&self, separator: char) -> String {
-        todo!()
-    }
-
-    fn substring_before_last_str(&self, separator: &str) -> String {
-        todo!()
-    }
-
-    fn substring_before_str(&self, separator: &str) -> String {
-        todo!()
-    }
-
-    fn substring_between(&self, open: &str, close: &str) -> String {
-        todo!()
-    }
-
-    fn substring_between_tag(&self, tag: &str) -> String {
-        todo!()
-    }
-
-    fn swap_case(&self) -> String {
-        todo!()
-    }
-
-    fn trim(&self) -> String {
-        todo!()
-    }
-
-    fn truncate(&self, max_width: usize) -> String {
-        todo!()
-    }
-
-    fn truncate_with_offset(&self, offset: usize, max_width: usize) -> String {
-        todo!()
-    }
-
-    fn uncapitalize(&self) -> String {
-        todo!()
-    }
-
-    fn unwrap_from_char(&self, wrap_char: char) -> String {
-        todo!()
+        abbrev_marker: &str,
+        max_width: usize,
+    ) -> UtilsResult<Cow<'_, str>> {
+        self.abbreviate_with_full_opt(abbrev_marker, 0, max_width)
     }
 
-    fn unwrap_from_str(&self, wrap_str: &str) -> String {
-        todo!()
-    }
+    fn abbreviate_with_full_opt(
+        &self,
+        abbrev_marker: &str,
+        mut offset: isize,
+        max_width: usize,
+    ) -> UtilsResult<Cow<'_, str>> {
+        let s = self.as_ref();
+        if s.is_not_empty() && abbrev_marker == EMPTY && max_width > 0 {
+            return Ok(Cow::Borrowed(s.substring(0..max_width)));
+        }
 
-    fn upper_case(&self) -> String {
-        todo!()
-    }
+        if StringUtils::is_any_empty([s, abbrev_marker]) {
+            return Ok(Cow::Borrowed(s));
+        }
 
-    fn wrap(&self, wrap_with: char) -> String {
-        todo!()
-    }
+        let abbrev_marker_length = abbrev_marker.chars().count();
+        let min_abbrev_width = abbrev_marker_length + 1;
+        let min_abbrev_width_offset = abbrev_marker_length + abbrev_marker_length + 1;
 
-    fn wrap_if_missing(&self, wrap_with: char) -> String {
-        todo!()
-    }
+        if max_width < min_abbrev_width {
+            return Err(InvalidArgument(format!(
+                "Minimum abbreviation width is {}",
+                min_abbrev_width
+            )));
+        }
 
-    fn wrap_if_missing_str(&self, wrap_with: &str) -> String {
-        todo!()
-    }
+        let str_len = s.chars().count() as isize;
+        if str_len <= max_width as isize {
+            return Ok(Cow::Borrowed(s));
+        }
+        offset = offset.clamp(0, str_len);
+        if str_len - offset < (max_width - abbrev_marker_length) as isize {
+            offset = str_len - (max_width - abbrev_marker_length) as isize
+        }
+        if offset <= (abbrev_marker_length + 1) as isize {
+            return Ok(Cow::Owned(
+                String::from(s.substring(0..(max_width - abbrev_marker_length))) + abbrev_marker,
+            ));
+        }
+        if max_width < min_abbrev_width_offset {
+            return Err(InvalidArgument(format!(
+                "Minimum abbreviation width with offset is {}",
+                min_abbrev_width_offset
+            )));
+        }
+        if (offset + (max_width - abbrev_marker_length) as isize) < str_len {
+            let rest = s.substring(offset as usize..).abbreviate_with_full_opt(
+                abbrev_marker,
+                0,
+                max_width - abbrev_marker_length,
+            )?;
+            return Ok(Cow::Owned(String::from(abbrev_marker) + &rest));
+        }
 
-    fn wrap_with_str(&self, wrap_with: &str) -> String {
-        todo!()
+        Ok(Cow::Owned(
+            String::from(abbrev_marker)
+                + s.substring((str_len - (max_width - abbrev_marker_length) as isize) as usize..),
+        ))
     }
-}
 
-impl StringUtilsExt for String {
-    fn abbreviate(&self, max_width: usize) -> UtilsResult<String> {
-        self.as_str().abbreviate(max_width)
+    fn abbreviate_to_width(&self, max_width: usize) -> UtilsResult<Cow<'_, str>> {
+        self.abbreviate_to_width_with_full_opt("...", 0, max_width)
     }
 
-    fn abbreviate_with_abbrev_marker(
+    fn abbreviate_to_width_with_abbrev_marker(
         &self,
         abbrev_marker: &str,
         max_width: usize,
-    ) -> UtilsResult<String> {
-        self.as_str()
-            .abbreviate_with_abbrev_marker(abbrev_marker, max_width)
+    ) -> UtilsResult<Cow<'_, str>> {
+        self.abbreviate_to_width_with_full_opt(abbrev_marker, 0, max_width)
     }
 
-    fn abbreviate_with_full_opt(
+    fn abbreviate_to_width_with_full_opt(
         &self,
         abbrev_marker: &str,
         offset: isize,
         max_width: usize,
-    ) -> UtilsResult<String> {
-        self.as_str()
-            .abbreviate_with_full_opt(abbrev_marker, offset, max_width)
+    ) -> UtilsResult<Cow<'_, str>> {
+        let s = self.as_ref();
+        if s.is_not_empty() && abbrev_marker == EMPTY && max_width > 0 {
+            return Ok(Cow::Borrowed(s.truncate_to_width(max_width)));
+        }
+
+        if StringUtils::is_any_empty([s, abbrev_marker]) {
+            return Ok(Cow::Borrowed(s));
+        }
+
+        let abbrev_marker_width = abbrev_marker.display_width();
+        let min_abbrev_width = abbrev_marker_width + 1;
+        let min_abbrev_width_offset = abbrev_marker_width + abbrev_marker_width + 1;
+
+        if max_width < min_abbrev_width {
+            return Err(InvalidArgument(format!(
+                "Minimum abbreviation width is {}",
+                min_abbrev_width
+            )));
+        }
+
+        let str_width = s.display_width();
+        if str_width <= max_width {
+            return Ok(Cow::Borrowed(s));
+        }
+        // From here on `offset` is the column where the char at `offset` starts.
+        let keep_width = max_width - abbrev_marker_width;
+        let mut offset = width_before(s, offset.max(0) as usize).min(str_width);
+        if str_width - offset < keep_width {
+            offset = str_width - keep_width;
+        }
+        if offset <= abbrev_marker_width + 1 {
+            return Ok(Cow::Owned(
+                String::from(s.truncate_to_width(keep_width)) + abbrev_marker,
+            ));
+        }
+        if max_width < min_abbrev_width_offset {
+            return Err(InvalidArgument(format!(
+                "Minimum abbreviation width with offset is {}",
+                min_abbrev_width_offset
+            )));
+        }
+        if offset + keep_width < str_width {
+            let rest = skip_width(s, offset).abbreviate_to_width_with_full_opt(
+                abbrev_marker,
+                0,
+                keep_width,
+            )?;
+            return Ok(Cow::Owned(String::from(abbrev_marker) + &rest));
+        }
+
+        Ok(Cow::Owned(
+            String::from(abbrev_marker) + last_to_width(s, keep_width),
+        ))
     }
 
-    fn abbreviate_middle(&self, middle: &str, length: usize) -> UtilsResult<String> {
-        todo!()
+    fn abbreviate_middle(&self, middle: &str, length: usize) -> Cow<'_, str> {
+        let s = self.as_ref();
+        let str_len = s.chars().count();
+        let middle_len = middle.chars().count();
+        if StringUtils::is_any_empty([s, middle]) || length >= str_len || length < middle_len + 2 {
+            return Cow::Borrowed(s);
+        }
+        let target_len = length - middle_len;
+        let start_offset = target_len / 2 + target_len % 2;
+        let end_offset = str_len - target_len / 2;
+        Cow::Owned(format!(
+            "{}{}{}",
+            s.substring(..start_offset),
+            middle,
+            s.substring(end_offset..)
+        ))
     }
 
-    fn abbreviate_with_offset(&self, offset: isize, max_width: usize) -> UtilsResult<String> {
-        todo!()
+    fn abbreviate_with_offset(&self, offset: isize, max_width: usize) -> UtilsResult<Cow<'_, str>> {
+        self.abbreviate_with_full_opt("...", offset, max_width)
     }
 
-    fn append_if_missing(&self, suffix: &str, ignore_case: bool) -> String {
-        todo!()
+    fn append_if_missing(&self, suffix: &str, ignore_case: bool) -> Cow<'_, str> {
+        self.append_if_missing_one_of(suffix, &[], ignore_case)
     }
 
     fn append_if_missing_one_of(
@@ -1034,289 +1740,514 @@ impl StringUtilsExt for String {
         suffix: &str,
         suffixes: &[&str],
         ignore_case: bool,
-    ) -> String {
-        todo!()
+    ) -> Cow<'_, str> {
+        let s = self.as_ref();
+        if str::is_empty(suffix)
+            || s.end_with(suffix, ignore_case)
+            || suffixes.iter().any(|other| s.end_with(other, ignore_case))
+        {
+            return Cow::Borrowed(s);
+        }
+        Cow::Owned(String::from(s) + suffix)
+    }
+
+    fn capitalize(&self) -> Cow<'_, str> {
+        map_first_char(self.as_ref(), to_titlecase)
+    }
+
+    fn center(&self, size: usize) -> Cow<'_, str> {
+        self.center_with_char(size, ' ')
     }
 
-    fn capitalize(&self) -> String {
-        todo!()
+    fn center_with(&self, size: usize, pad_str: &str) -> Cow<'_, str> {
+        let s = self.as_ref();
+        let pad_str = if pad_str.is_empty() { SPACE } else { pad_str };
+        let pads = size.saturating_sub(s.chars().count());
+        if pads == 0 {
+            return Cow::Borrowed(s);
+        }
+        let mut result = padding(pads / 2, pad_str);
+        result.push_str(s);
+        result.push_str(&padding(pads - pads / 2, pad_str));
+        Cow::Owned(result)
     }
 
-    fn center(&self, size: usize) -> String {
-        todo!()
+    fn center_with_char(&self, size: usize, pad_char: char) -> Cow<'_, str> {
+        self.center_with(size, pad_char.encode_utf8(&mut [0; 4]))
     }
 
-    fn center_with(&self, size: usize, pad_str: &str) -> String {
-        todo!()
+    fn center_to_width(&self, width: usize) -> Cow<'_, str> {
+        self.center_to_width_with(width, SPACE, PadPolicy::FillWithSpace)
     }
 
-    fn center_with_char(&self, size: usize, pad_char: &str) -> String {
-        todo!()
+    fn center_to_width_with(&self, width: usize, pad_str: &str, policy: PadPolicy) -> Cow<'_, str> {
+        let s = self.as_ref();
+        let pads = width.saturating_sub(s.display_width());
+        if pads == 0 {
+            return Cow::Borrowed(s);
+        }
+        let mut result = padding_to_width(pads / 2, pad_str, policy);
+        result.push_str(s);
+        result.push_str(&padding_to_width(pads - pads / 2, pad_str, policy));
+        Cow::Owned(result)
     }
 
-    fn chomp(&self) -> String {
-        todo!()
+    fn center_to_width_with_char(
+        &self,
+        width: usize,
+        pad_char: char,
+        policy: PadPolicy,
+    ) -> Cow<'_, str> {
+        self.center_to_width_with(width, pad_char.encode_utf8(&mut [0; 4]), policy)
+    }
+
+    fn chomp(&self) -> &str {
+        let s = self.as_ref();
+        match s.strip_suffix(LF) {
+            Some(s) => s.strip_suffix(CR).unwrap_or(s),
+            None => s.strip_suffix(CR).unwrap_or(s),
+        }
     }
 
-    fn chomp_specified(&self, separator: &str) -> String {
-        todo!()
+    fn chomp_specified(&self, separator: &str) -> &str {
+        let s = self.as_ref();
+        s.strip_suffix(separator).unwrap_or(s)
     }
 
-    fn chop(&self) -> String {
-        todo!()
+    fn chop(&self) -> &str {
+        let s = self.as_ref();
+        let mut chars = s.chars();
+        if chars.next_back() == Some('\n') && chars.as_str().ends_with(CR) {
+            chars.next_back();
+        }
+        chars.as_str()
     }
 
-    fn compare(&self, str2: &str) -> i32 {
-        todo!()
+    fn compare(&self, str2: &str) -> Ordering {
+        let s = self.as_ref();
+        str::cmp(s, str2)
     }
 
-    fn compare_ignore_case(&self, str2: &str) -> i32 {
-        todo!()
+    fn compare_ignore_case(&self, str2: &str) -> Ordering {
+        let s = self.as_ref();
+        s.chars().map(fold_case).cmp(str2.chars().map(fold_case))
     }
 
     fn contains(&self, search: &str) -> bool {
-        todo!()
+        let s = self.as_ref();
+        str::contains(s, search)
     }
 
     fn contains_char(&self, search_char: char) -> bool {
-        todo!()
+        let s = self.as_ref();
+        str::contains(s, search_char)
     }
 
     fn contains_any(&self, searches: &[&str]) -> bool {
-        todo!()
+        let s = self.as_ref();
+        s.contains_any_with(searches, |s, search| str::contains(s, search))
     }
 
     fn contains_any_char(&self, searches: &[char]) -> bool {
-        todo!()
+        let s = self.as_ref();
+        s.chars().any(|c| searches.contains(&c))
     }
 
     fn contains_any_in(&self, searches_str: &str) -> bool {
-        todo!()
+        let s = self.as_ref();
+        s.chars().any(|c| str::contains(searches_str, c))
     }
 
     fn contains_any_ignore_case(&self, searches: &[&str]) -> bool {
-        todo!()
+        let s = self.as_ref();
+        s.contains_any_with(searches, |s, search| s.contains_ignore_case(search))
     }
 
     fn contains_any_with<T>(&self, searches: &[&str], test: T) -> bool
     where
         T: Fn(&str, &str) -> bool,
     {
-        todo!()
+        let s = self.as_ref();
+        if str::is_empty(s) {
+            return false;
+        }
+        searches.iter().any(|search| test(s, search))
     }
 
     fn contains_ignore_case(&self, search: &str) -> bool {
-        todo!()
+        self.index_of_ignore_case(search).is_some()
     }
 
     fn contains_none(&self, searches: &[char]) -> bool {
-        todo!()
+        let s = self.as_ref();
+        !s.chars().any(|c| searches.contains(&c))
     }
 
     fn contains_none_in(&self, invalid_chars: &str) -> bool {
-        todo!()
+        let s = self.as_ref();
+        !s.chars().any(|c| str::contains(invalid_chars, c))
     }
 
     fn contains_only(&self, valid: &[char]) -> bool {
-        todo!()
+        let s = self.as_ref();
+        if str::is_empty(s) {
+            return true;
+        }
+        s.chars().all(|c| valid.contains(&c))
     }
 
     fn contains_only_in(&self, valid_chars: &str) -> bool {
-        todo!()
+        let s = self.as_ref();
+        if str::is_empty(s) {
+            return true;
+        }
+        s.chars().all(|c| str::contains(valid_chars, c))
     }
 
     fn contains_whitespace(&self) -> bool {
-        todo!()
+        let s = self.as_ref();
+        s.chars().any(char::is_whitespace)
     }
 
     fn count_matches(&self, ch: char) -> u64 {
-        todo!()
+        let s = self.as_ref();
+        s.chars().filter(|&c| c == ch).count() as u64
     }
 
     fn count_matches_str(&self, sub: &str) -> u64 {
-        todo!()
+        let s = self.as_ref();
+        if sub.is_empty() {
+            return 0;
+        }
+        s.matches(sub).count() as u64
+    }
+
+    fn default_if_blank<'a>(&'a self, default_str: &'a str) -> &'a str {
+        let s = self.as_ref();
+        if s.is_blank() {
+            default_str
+        } else {
+            s
+        }
     }
 
-    fn default_if_blank(&self, default_str: &str) -> String {
-        todo!()
+    fn default_if_empty<'a>(&'a self, default_str: &'a str) -> &'a str {
+        let s = self.as_ref();
+        if str::is_empty(s) {
+            default_str
+        } else {
+            s
+        }
     }
 
-    fn default_if_empty(&self, default_str: &str) -> String {
-        todo!()
+    fn default_string(&self) -> &str {
+        self.as_ref()
     }
 
-    fn default_string(&self) -> String {
-        todo!()
+    fn delete_whitespace(&self) -> Cow<'_, str> {
+        let s = self.as_ref();
+        if s.contains_whitespace() {
+            Cow::Owned(s.chars().filter(|c| !c.is_whitespace()).collect())
+        } else {
+            Cow::Borrowed(s)
+        }
     }
 
-    fn delete_whitespace(&self) -> String {
-        todo!()
+    fn delete_whitespace_to_none(&self) -> Option<Cow<'_, str>> {
+        Some(self.delete_whitespace()).filter(|deleted| !deleted.is_blank())
     }
 
-    fn difference(&self, str2: &str) -> String {
-        todo!()
+    fn difference<'a>(&self, str2: &'a str) -> &'a str {
+        match self.index_of_difference(str2) {
+            Some(at) => str2.substring(at..),
+            None => EMPTY,
+        }
     }
 
     fn digits(&self) -> String {
-        todo!()
+        let s = self.as_ref();
+        s.chars().filter(|&c| is_digit(c)).collect()
+    }
+
+    fn display_width(&self) -> usize {
+        let s = self.as_ref();
+        s.graphemes(true).map(UnicodeWidthStr::width).sum()
     }
 
     fn end_with(&self, suffix: &str, ignore_case: bool) -> bool {
-        todo!()
+        let s = self.as_ref();
+        if ignore_case {
+            strip_suffix_ignore_case(s, suffix).is_some()
+        } else {
+            str::ends_with(s, suffix)
+        }
     }
 
     fn end_with_any(&self, searches: &[&str]) -> bool {
-        todo!()
+        let s = self.as_ref();
+        !str::is_empty(s) && searches.iter().any(|search| str::ends_with(s, search))
     }
 
     fn end_with_consider_case(&self, suffix: &str) -> bool {
-        todo!()
+        self.end_with(suffix, false)
     }
 
     fn end_with_ignore_case(&self, suffix: &str) -> bool {
-        todo!()
+        self.end_with(suffix, true)
     }
 
     fn equals(&self, str2: &str) -> bool {
-        todo!()
+        let s = self.as_ref();
+        s == str2
     }
 
     fn equals_any(&self, searches: &[&str]) -> bool {
-        todo!()
+        let s = self.as_ref();
+        searches.contains(&s)
     }
 
     fn equals_any_ignore_case(&self, searches: &[&str]) -> bool {
-        todo!()
+        searches
+            .iter()
+            .any(|search| self.equals_ignore_case(search))
     }
 
     fn equals_ignore_case(&self, str2: &str) -> bool {
-        todo!()
+        let s = self.as_ref();
+        s.chars().count() == str2.chars().count()
+            && s.chars()
+                .zip(str2.chars())
+                .all(|(a, b)| char_eq_ignore_case(a, b))
     }
 
-    fn if_blank<T>(&self, default_supplier: T) -> String
+    fn if_blank<T>(&self, default_supplier: T) -> Cow<'_, str>
     where
-        T: Fn() -> String,
+        T: FnOnce() -> String,
     {
-        todo!()
+        let s = self.as_ref();
+        if s.is_blank() {
+            Cow::Owned(default_supplier())
+        } else {
+            Cow::Borrowed(s)
+        }
     }
 
-    fn if_empty<T>(&self, default_supplier: T) -> String
+    fn if_empty<T>(&self, default_supplier: T) -> Cow<'_, str>
     where
-        T: Fn() -> String,
+        T: FnOnce() -> String,
     {
-        todo!()
+        let s = self.as_ref();
+        if str::is_empty(s) {
+            Cow::Owned(default_supplier())
+        } else {
+            Cow::Borrowed(s)
+        }
     }
 
     fn index_of(&self, search: &str) -> Option<usize> {
-        todo!()
+        self.index_of_starting_from(search, 0)
     }
 
     fn index_of_any(&self, searches: &[&str]) -> Option<usize> {
-        todo!()
+        let s = self.as_ref();
+        searches
+            .iter()
+            .filter_map(|search| s.index_of(search))
+            .min()
     }
 
     fn index_of_any_but(&self, search_chars: &[char]) -> Option<usize> {
-        todo!()
+        let s = self.as_ref();
+        if str::is_empty(s) || search_chars.is_empty() {
+            return None;
+        }
+        s.chars().position(|c| !search_chars.contains(&c))
     }
 
     fn index_of_any_but_in(&self, search_chars: &str) -> Option<usize> {
-        todo!()
+        let s = self.as_ref();
+        let search_chars: Vec<char> = search_chars.chars().collect();
+        s.index_of_any_but(&search_chars)
     }
 
     fn index_of_any_char(&self, search_chars: &[char]) -> Option<usize> {
-        todo!()
+        let s = self.as_ref();
+        s.chars().position(|c| search_chars.contains(&c))
     }
 
     fn index_of_any_in(&self, search_chars: &str) -> Option<usize> {
-        todo!()
+        let s = self.as_ref();
+        s.chars().position(|c| search_chars.contains(c))
     }
 
     fn index_of_char(&self, search_char: char) -> Option<usize> {
-        todo!()
+        let s = self.as_ref();
+        s.chars().position(|c| c == search_char)
     }
 
     fn index_of_char_starting_from(&self, search_char: char, start_pos: usize) -> Option<usize> {
-        todo!()
+        let s = self.as_ref();
+        s.chars()
+            .skip(start_pos)
+            .position(|c| c == search_char)
+            .map(|i| i + start_pos)
     }
 
     fn index_of_starting_from(&self, search: &str, start_pos: usize) -> Option<usize> {
-        todo!()
+        let s = self.as_ref();
+        let from = byte_index(s, start_pos);
+        s[from..].find(search).map(|i| char_index(s, from + i))
     }
 
     fn index_of_difference(&self, str2: &str) -> Option<usize> {
-        todo!()
+        let s = self.as_ref();
+        if s == str2 {
+            return None;
+        }
+        Some(
+            s.chars()
+                .zip(str2.chars())
+                .take_while(|(a, b)| a == b)
+                .count(),
+        )
     }
 
     fn index_of_ignore_case(&self, search: &str) -> Option<usize> {
-        todo!()
+        self.index_of_ignore_case_starting_from(search, 0)
     }
 
     fn index_of_ignore_case_starting_from(&self, search: &str, start_pos: usize) -> Option<usize> {
-        todo!()
+        let s = self.as_ref();
+        let str_len = s.chars().count();
+        let search_len = search.chars().count();
+        if search_len > str_len || start_pos > str_len - search_len {
+            return None;
+        }
+        if search.is_empty() {
+            return Some(start_pos);
+        }
+        s.char_indices()
+            .skip(start_pos)
+            .take(str_len - search_len - start_pos + 1)
+            .position(|(i, _)| starts_with_ignore_case(&s[i..], search))
+            .map(|i| i + start_pos)
     }
 
     fn is_alpha(&self) -> bool {
-        todo!()
+        let s = self.as_ref();
+        s.is_not_empty() && s.chars().all(is_letter)
     }
 
     fn is_alphanumeric(&self) -> bool {
-        todo!()
+        let s = self.as_ref();
+        s.is_not_empty() && s.chars().all(|c| is_letter(c) || is_digit(c))
     }
 
     fn is_alphanumeric_space(&self) -> bool {
-        todo!()
+        let s = self.as_ref();
+        s.chars().all(|c| is_letter(c) || is_digit(c) || c == ' ')
     }
 
     fn is_alpha_space(&self) -> bool {
-        todo!()
+        let s = self.as_ref();
+        s.chars().all(|c| is_letter(c) || c == ' ')
+    }
+
+    fn is_ascii_alpha(&self) -> bool {
+        let s = self.as_ref();
+        s.is_not_empty() && s.chars().all(|c| c.is_ascii_alphabetic())
+    }
+
+    fn is_ascii_alphanumeric(&self) -> bool {
+        let s = self.as_ref();
+        s.is_not_empty() && s.chars().all(|c| c.is_ascii_alphanumeric())
+    }
+
+    fn is_ascii_alphanumeric_space(&self) -> bool {
+        let s = self.as_ref();
+        s.chars().all(|c| c.is_ascii_alphanumeric() || c == ' ')
+    }
+
+    fn is_ascii_alpha_space(&self) -> bool {
+        let s = self.as_ref();
+        s.chars().all(|c| c.is_ascii_alphabetic() || c == ' ')
+    }
+
+    fn is_ascii_numeric(&self) -> bool {
+        let s = self.as_ref();
+        s.is_not_empty() && s.chars().all(|c| c.is_ascii_digit())
+    }
+
+    fn is_ascii_numeric_space(&self) -> bool {
+        let s = self.as_ref();
+        s.chars().all(|c| c.is_ascii_digit() || c == ' ')
     }
 
     fn is_ascii_printable(&self) -> bool {
-        todo!()
+        let s = self.as_ref();
+        s.chars().all(|c| (' '..='~').contains(&c))
     }
 
     fn is_blank(&self) -> bool {
-        todo!()
+        let s = self.as_ref();
+        s.chars().all(is_java_whitespace)
     }
 
     fn is_empty(&self) -> bool {
-        todo!()
+        let s = self.as_ref();
+        str::is_empty(s)
     }
 
     fn is_mixed_case(&self) -> bool {
-        todo!()
+        let s = self.as_ref();
+        s.chars().any(char::is_uppercase) && s.chars().any(char::is_lowercase)
     }
 
     fn is_not_blank(&self) -> bool {
-        todo!()
+        let s = self.as_ref();
+        !s.is_blank()
     }
 
     fn is_not_empty(&self) -> bool {
-        todo!()
+        let s = self.as_ref();
+        !str::is_empty(s)
     }
 
     fn is_numeric(&self) -> bool {
-        todo!()
+        let s = self.as_ref();
+        s.is_not_empty() && s.chars().all(is_digit)
     }
 
     fn is_numeric_space(&self) -> bool {
-        todo!()
+        let s = self.as_ref();
+        s.chars().all(|c| is_digit(c) || c == ' ')
     }
 
     fn is_whitespace(&self) -> bool {
-        todo!()
+        let s = self.as_ref();
+        s.chars().all(is_java_whitespace)
     }
 
     fn last_index_of(&self, search: &str) -> Option<usize> {
-        todo!()
+        let s = self.as_ref();
+        s.rfind(search).map(|i| char_index(s, i))
     }
 
     fn last_index_of_any(&self, searches: &[&str]) -> Option<usize> {
-        todo!()
+        let s = self.as_ref();
+        searches
+            .iter()
+            .filter_map(|search| s.last_index_of(search))
+            .max()
     }
 
     fn last_index_of_char(&self, search_char: char) -> Option<usize> {
-        todo!()
+        let s = self.as_ref();
+        s.chars()
+            .enumerate()
+            .filter(|&(_, c)| c == search_char)
+            .last()
+            .map(|(i, _)| i)
     }
 
     fn last_index_of_char_starting_from(
@@ -1324,11 +2255,17 @@ impl StringUtilsExt for String {
         search_char: char,
         start_pos: usize,
     ) -> Option<usize> {
-        todo!()
+        let s = self.as_ref();
+        s.chars()
+            .take(start_pos.saturating_add(1))
+            .enumerate()
+            .filter(|&(_, c)| c == search_char)
+            .last()
+            .map(|(i, _)| i)
     }
 
     fn last_index_of_ignore_case(&self, search: &str) -> Option<usize> {
-        todo!()
+        self.last_index_of_ignore_case_starting_from(search, usize::MAX)
     }
 
     fn last_index_of_ignore_case_starting_from(
@@ -1336,55 +2273,168 @@ impl StringUtilsExt for String {
         search: &str,
         start_pos: usize,
     ) -> Option<usize> {
-        todo!()
+        let s = self.as_ref();
+        let str_len = s.chars().count();
+        let search_len = search.chars().count();
+        if search_len > str_len {
+            return None;
+        }
+        let start_pos = start_pos.min(str_len - search_len);
+        if search.is_empty() {
+            return Some(start_pos);
+        }
+        let offsets: Vec<usize> = s.char_indices().map(|(i, _)| i).collect();
+        (0..=start_pos)
+            .rev()
+            .find(|&i| starts_with_ignore_case(&s[offsets[i]..], search))
     }
 
     fn last_index_of_starting_from(&self, search: &str, start_pos: usize) -> Option<usize> {
-        todo!()
+        let s = self.as_ref();
+        let mut end = byte_index(s, start_pos).saturating_add(search.len());
+        if end >= s.len() {
+            end = s.len();
+        } else {
+            while !s.is_char_boundary(end) {
+                end -= 1;
+            }
+        }
+        s[..end].rfind(search).map(|i| char_index(s, i))
     }
 
     fn last_ordinal_index_of(&self, search: &str, ordinal: usize) -> Option<usize> {
-        todo!()
+        let s = self.as_ref();
+        if ordinal == 0 {
+            return None;
+        }
+        if search.is_empty() {
+            return Some(s.chars().count());
+        }
+        let mut index = None;
+        for _ in 0..ordinal {
+            let start_pos = match index {
+                None => usize::MAX,
+                Some(0) => return None,
+                Some(i) => i - 1,
+            };
+            index = Some(s.last_index_of_starting_from(search, start_pos)?);
+        }
+        index
+    }
+
+    fn left(&self, len: usize) -> &str {
+        self.substring(..len)
+    }
+
+    fn left_pad(&self, size: usize) -> Cow<'_, str> {
+        self.left_pad_with(size, ' ')
+    }
+
+    fn left_pad_with(&self, size: usize, pad_char: char) -> Cow<'_, str> {
+        self.left_pad_with_str(size, pad_char.encode_utf8(&mut [0; 4]))
     }
 
-    fn left(&self, len: usize) -> String {
-        todo!()
+    fn left_pad_with_str(&self, size: usize, pad_str: &str) -> Cow<'_, str> {
+        let s = self.as_ref();
+        let pad_str = if pad_str.is_empty() { SPACE } else { pad_str };
+        let pads = size.saturating_sub(s.chars().count());
+        if pads == 0 {
+            return Cow::Borrowed(s);
+        }
+        Cow::Owned(padding(pads, pad_str) + s)
     }
 
-    fn left_pad(&self, size: usize) -> String {
-        todo!()
+    fn left_pad_to_width(&self, width: usize) -> Cow<'_, str> {
+        self.left_pad_to_width_with_str(width, SPACE, PadPolicy::FillWithSpace)
     }
 
-    fn left_pad_with(&self, size: usize, pad_char: char) -> String {
-        todo!()
+    fn left_pad_to_width_with(
+        &self,
+        width: usize,
+        pad_char: char,
+        policy: PadPolicy,
+    ) -> Cow<'_, str> {
+        self.left_pad_to_width_with_str(width, pad_char.encode_utf8(&mut [0; 4]), policy)
     }
 
-    fn left_pad_with_str(&self, size: usize, pad_str: &str) -> String {
-        todo!()
+    fn left_pad_to_width_with_str(
+        &self,
+        width: usize,
+        pad_str: &str,
+        policy: PadPolicy,
+    ) -> Cow<'_, str> {
+        let s = self.as_ref();
+        let pads = width.saturating_sub(s.display_width());
+        if pads == 0 {
+            return Cow::Borrowed(s);
+        }
+        Cow::Owned(padding_to_width(pads, pad_str, policy) + s)
     }
 
     fn lower_case(&self) -> String {
-        todo!()
+        let s = self.as_ref();
+        str::to_lowercase(s)
     }
 
-    fn mid(&self, pos: usize, len: usize) -> String {
-        todo!()
+    fn mid(&self, pos: usize, len: usize) -> &str {
+        self.substring(pos..pos.saturating_add(len))
+    }
+
+    fn normalize_space(&self) -> Cow<'_, str> {
+        let s = self.as_ref();
+        if is_space_normalized(s) {
+            return Cow::Borrowed(s);
+        }
+        let mut words = s.split_whitespace();
+        let mut normalized = String::with_capacity(s.len());
+        if let Some(first) = words.next() {
+            normalized.push_str(first);
+            for word in words {
+                normalized.push(' ');
+                normalized.push_str(word);
+            }
+        }
+        Cow::Owned(normalized)
     }
 
-    fn normalize_space(&self) -> String {
-        todo!()
+    fn normalize_space_to_none(&self) -> Option<Cow<'_, str>> {
+        Some(self.normalize_space()).filter(|normalized| !normalized.is_blank())
     }
 
     fn ordinal_index_of(&self, search: &str, ordinal: usize) -> Option<usize> {
-        todo!()
+        let s = self.as_ref();
+        if ordinal == 0 {
+            return None;
+        }
+        if search.is_empty() {
+            return Some(0);
+        }
+        let mut index = None;
+        for _ in 0..ordinal {
+            let start_pos = index.map_or(0, |i| i + 1);
+            index = Some(s.index_of_starting_from(search, start_pos)?);
+        }
+        index
     }
 
     fn overlay(&self, overlay: &str, start: usize, end: usize) -> String {
-        todo!()
+        let s = self.as_ref();
+        let len = s.chars().count();
+        let (start, end) = (start.min(len), end.min(len));
+        let (start, end) = if start > end {
+            (end, start)
+        } else {
+            (start, end)
+        };
+        let mut result = String::with_capacity(s.len() + overlay.len());
+        result.push_str(&s[..byte_index(s, start)]);
+        result.push_str(overlay);
+        result.push_str(&s[byte_index(s, end)..]);
+        result
     }
 
-    fn prepend_if_missing(&self, prefix: &str, ignore_case: bool) -> String {
-        todo!()
+    fn prepend_if_missing(&self, prefix: &str, ignore_case: bool) -> Cow<'_, str> {
+        self.prepend_if_missing_one_of(prefix, &[], ignore_case)
     }
 
     fn prepend_if_missing_one_of(
@@ -1392,64 +2442,197 @@ impl StringUtilsExt for String {
         prefix: &str,
         prefixes: &[&str],
         ignore_case: bool,
-    ) -> String {
-        todo!()
+    ) -> Cow<'_, str> {
+        let s = self.as_ref();
+        if str::is_empty(prefix)
+            || self.starts_with(prefix, ignore_case)
+            || prefixes
+                .iter()
+                .any(|other| self.starts_with(other, ignore_case))
+        {
+            return Cow::Borrowed(s);
+        }
+        Cow::Owned(String::from(prefix) + s)
+    }
+
+    fn remove(&self, remove: &str) -> Cow<'_, str> {
+        let s = self.as_ref();
+        s.remove_checked(remove)
+            .map_or(Cow::Borrowed(s), Cow::Owned)
+    }
+
+    fn remove_checked(&self, remove: &str) -> Option<String> {
+        let s = self.as_ref();
+        if remove.is_empty() || !str::contains(s, remove) {
+            return None;
+        }
+        Some(s.replace(remove, EMPTY))
+    }
+
+    fn remove_char(&self, remove: char) -> Cow<'_, str> {
+        let s = self.as_ref();
+        s.remove_char_checked(remove)
+            .map_or(Cow::Borrowed(s), Cow::Owned)
+    }
+
+    fn remove_char_checked(&self, remove: char) -> Option<String> {
+        let s = self.as_ref();
+        if !str::contains(s, remove) {
+            return None;
+        }
+        Some(s.replace(remove, EMPTY))
+    }
+
+    fn remove_all(&self, remove: &str) -> Cow<'_, str> {
+        self.remove(remove)
+    }
+
+    #[cfg(feature = "regex")]
+    fn remove_all_regex(&self, regex: &str) -> UtilsResult<Cow<'_, str>> {
+        self.replace_all_regex(regex, EMPTY)
+    }
+
+    fn remove_end(&self, remove: &str) -> &str {
+        let s = self.as_ref();
+        s.remove_end_checked(remove).unwrap_or(s)
+    }
+
+    fn remove_end_checked(&self, remove: &str) -> Option<&str> {
+        let s = self.as_ref();
+        if remove.is_empty() {
+            return None;
+        }
+        s.strip_suffix(remove)
+    }
+
+    fn remove_end_ignore_case(&self, remove: &str) -> &str {
+        let s = self.as_ref();
+        s.remove_end_ignore_case_checked(remove).unwrap_or(s)
+    }
+
+    fn remove_end_ignore_case_checked(&self, remove: &str) -> Option<&str> {
+        let s = self.as_ref();
+        strip_suffix_ignore_case(s, remove)
+    }
+
+    fn remove_first(&self, remove: &str) -> Cow<'_, str> {
+        let s = self.as_ref();
+        s.remove_first_checked(remove)
+            .map_or(Cow::Borrowed(s), Cow::Owned)
+    }
+
+    fn remove_first_checked(&self, remove: &str) -> Option<String> {
+        let s = self.as_ref();
+        if remove.is_empty() {
+            return None;
+        }
+        let pos = s.find(remove)?;
+        Some(String::from(&s[..pos]) + &s[pos + remove.len()..])
     }
 
-    fn remove(&self, remove: &str) -> String {
-        todo!()
+    #[cfg(feature = "regex")]
+    fn remove_first_regex(&self, regex: &str) -> UtilsResult<Cow<'_, str>> {
+        self.replace_first_regex(regex, EMPTY)
     }
 
-    fn remove_char(&self, remove: char) -> String {
-        todo!()
+    fn remove_ignore_case(&self, remove: &str) -> Cow<'_, str> {
+        let s = self.as_ref();
+        s.remove_ignore_case_checked(remove)
+            .map_or(Cow::Borrowed(s), Cow::Owned)
     }
 
-    fn remove_all(&self, remove: &str) -> String {
-        todo!()
+    fn remove_ignore_case_checked(&self, remove: &str) -> Option<String> {
+        let s = self.as_ref();
+        remove_all_ignore_case(s, remove)
     }
 
-    fn remove_end(&self, remove: &str) -> String {
-        todo!()
+    #[cfg(feature = "regex")]
+    fn remove_pattern(&self, regex: &str) -> UtilsResult<Cow<'_, str>> {
+        self.replace_pattern(regex, EMPTY)
     }
 
-    fn remove_end_ignore_case(&self, remove: &str) -> String {
-        todo!()
+    fn remove_start(&self, remove: &str) -> &str {
+        let s = self.as_ref();
+        s.remove_start_checked(remove).unwrap_or(s)
     }
 
-    fn remove_first(&self, remove: &str) -> String {
-        todo!()
+    fn remove_start_checked(&self, remove: &str) -> Option<&str> {
+        let s = self.as_ref();
+        if remove.is_empty() {
+            return None;
+        }
+        s.strip_prefix(remove)
     }
 
-    fn remove_ignore_case(&self, remove: &str) -> String {
-        todo!()
+    fn remove_start_char(&self, remove: char) -> &str {
+        let s = self.as_ref();
+        s.remove_start_char_checked(remove).unwrap_or(s)
     }
 
-    fn remove_start(&self, remove: &str) -> String {
-        todo!()
+    fn remove_start_char_checked(&self, remove: char) -> Option<&str> {
+        let s = self.as_ref();
+        s.strip_prefix(remove)
     }
 
-    fn remove_start_char(&self, remove: char) -> String {
-        todo!()
+    fn remove_start_ignore_case(&self, remove: &str) -> &str {
+        let s = self.as_ref();
+        s.remove_start_ignore_case_checked(remove).unwrap_or(s)
     }
 
-    fn remove_start_ignore_case(&self, remove: &str) -> String {
-        todo!()
+    fn remove_start_ignore_case_checked(&self, remove: &str) -> Option<&str> {
+        let s = self.as_ref();
+        strip_prefix_ignore_case(s, remove)
     }
 
     fn repeat(&self, repeat: usize) -> String {
-        todo!()
+        let s = self.as_ref();
+        str::repeat(s, repeat)
     }
 
-    fn replace_chars(&self, search: char, replace: char) -> String {
-        todo!()
+    #[cfg(feature = "regex")]
+    fn replace_all_regex(&self, regex: &str, replacement: &str) -> UtilsResult<Cow<'_, str>> {
+        let s = self.as_ref();
+        Ok(compile_regex(regex, false)?.replace_all(s, replacement))
     }
 
-    fn replace_chars_in(&self, search_chars: &str, replace_chars: &str) -> String {
-        todo!()
+    fn replace_chars(&self, search: char, replace: char) -> Cow<'_, str> {
+        let s = self.as_ref();
+        if !str::contains(s, search) {
+            return Cow::Borrowed(s);
+        }
+        Cow::Owned(s.replace(search, replace.encode_utf8(&mut [0; 4])))
     }
 
-    fn replace_each(&self, search_list: &[&str], replace_list: &[&str]) -> UtilsResult<String> {
-        todo!()
+    fn replace_chars_in(&self, search_chars: &str, replace_chars: &str) -> Cow<'_, str> {
+        let s = self.as_ref();
+        if !s.chars().any(|c| str::contains(search_chars, c)) {
+            return Cow::Borrowed(s);
+        }
+        let replace_chars: Vec<char> = replace_chars.chars().collect();
+        Cow::Owned(
+            s.chars()
+                .filter_map(
+                    |c| match search_chars.chars().position(|search| search == c) {
+                        Some(i) => replace_chars.get(i).copied(),
+                        None => Some(c),
+                    },
+                )
+                .collect(),
+        )
+    }
+
+    fn replace_each(
+        &self,
+        search_list: &[&str],
+        replace_list: &[&str],
+    ) -> UtilsResult<Cow<'_, str>> {
+        let s = self.as_ref();
+        if str::is_empty(s) || search_list.is_empty() || replace_list.is_empty() {
+            return Ok(Cow::Borrowed(s));
+        }
+        check_replace_lists(search_list, replace_list)?;
+        Ok(replace_each_once(s, search_list, replace_list, None)
+            .map_or(Cow::Borrowed(s), Cow::Owned))
     }
 
     fn replace_each_repeatedly(
@@ -1457,163 +2640,531 @@ impl StringUtilsExt for String {
         search_list: &[&str],
         replace_list: &[&str],
         time_to_live: Option<usize>,
-    ) -> UtilsResult<String> {
-        todo!()
+    ) -> UtilsResult<Cow<'_, str>> {
+        let s = self.as_ref();
+        if str::is_empty(s) || search_list.is_empty() || replace_list.is_empty() {
+            return Ok(Cow::Borrowed(s));
+        }
+        check_replace_lists(search_list, replace_list)?;
+
+        let passes = time_to_live.unwrap_or(search_list.len() + 1);
+        let mut text = Cow::Borrowed(s);
+        // Every text seen so far, with the pass it was fed into.
+        let mut seen = BTreeMap::new();
+        // The leftmost search string replaced by each pass.
+        let mut first_replaced = Vec::new();
+        // The replacements made by the previous pass, by their range in `text`.
+        let mut previous: Vec<Replacement> = Vec::new();
+        // `fed[i]` holds the search strings found inside a replacement for search string `i`.
+        let mut fed = vec![Vec::new(); search_list.len()];
+        for pass in 0..passes {
+            let mut replacements = Vec::new();
+            let Some(replaced) =
+                replace_each_once(&text, search_list, replace_list, Some(&mut replacements))
+            else {
+                return Ok(text);
+            };
+            for replacement in &replacements {
+                let end = replacement.found_at + search_list[replacement.search].len();
+                let inside = previous
+                    .partition_point(|earlier| earlier.output.start <= replacement.found_at)
+                    .checked_sub(1)
+                    .map(|k| &previous[k])
+                    .filter(|earlier| end <= earlier.output.end);
+                let Some(earlier) = inside else { continue };
+                if fed[earlier.search].contains(&replacement.search) {
+                    continue;
+                }
+                fed[earlier.search].push(replacement.search);
+                if let Some(path) = find_path(&fed, replacement.search, earlier.search) {
+                    return Err(ReplacementCycle(cycle_names(search_list, &path)));
+                }
+            }
+            first_replaced.push(replacements[0].search);
+            seen.insert(text.into_owned(), pass);
+            if let Some(&from) = seen.get(&replaced) {
+                return Err(ReplacementCycle(cycle_names(
+                    search_list,
+                    &first_replaced[from..],
+                )));
+            }
+            previous = replacements;
+            text = Cow::Owned(replaced);
+        }
+
+        let still_matching = (0..search_list.len())
+            .filter(|&i| !search_list[i].is_empty() && str::contains(&text, search_list[i]));
+        if let Some(cycle) = find_replacement_cycle(search_list, replace_list, still_matching) {
+            return Err(ReplacementCycle(cycle));
+        }
+        Ok(text)
+    }
+
+    fn replace_first(&self, search: &str, replace: &str) -> Cow<'_, str> {
+        let s = self.as_ref();
+        if str::is_empty(search) || !str::contains(s, search) {
+            return Cow::Borrowed(s);
+        }
+        Cow::Owned(s.replacen(search, replace, 1))
+    }
+
+    #[cfg(feature = "regex")]
+    fn replace_first_regex(&self, regex: &str, replacement: &str) -> UtilsResult<Cow<'_, str>> {
+        let s = self.as_ref();
+        Ok(compile_regex(regex, false)?.replace(s, replacement))
     }
 
-    fn replace_first(&self, search: &str, replace: &str) -> String {
-        todo!()
+    #[cfg(feature = "regex")]
+    fn replace_pattern(&self, regex: &str, replacement: &str) -> UtilsResult<Cow<'_, str>> {
+        let s = self.as_ref();
+        Ok(compile_regex(regex, true)?.replace_all(s, replacement))
     }
 
     fn reverse(&self) -> String {
-        todo!()
+        let s = self.as_ref();
+        s.graphemes(true).rev().collect()
+    }
+
+    fn reverse_code_points(&self) -> String {
+        let s = self.as_ref();
+        s.chars().rev().collect()
     }
 
     fn reverse_delimited(&self, separator_char: char) -> String {
-        todo!()
+        let s = self.as_ref();
+        let mut separator = [0; 4];
+        let separator = &*separator_char.encode_utf8(&mut separator);
+        let mut fields = Vec::new();
+        let mut start = 0;
+        for (i, grapheme) in s.grapheme_indices(true) {
+            if grapheme == separator {
+                fields.push(&s[start..i]);
+                start = i + grapheme.len();
+            }
+        }
+        fields.push(&s[start..]);
+        join_reversed(fields, separator)
+    }
+
+    fn reverse_delimited_code_points(&self, separator_char: char) -> String {
+        let s = self.as_ref();
+        join_reversed(
+            str::split(s, separator_char).collect(),
+            separator_char.encode_utf8(&mut [0; 4]),
+        )
+    }
+
+    fn right(&self, len: usize) -> &str {
+        let s = self.as_ref();
+        let str_len = s.chars().count();
+        s.substring(str_len.saturating_sub(len)..)
+    }
+
+    fn right_pad(&self, size: usize) -> Cow<'_, str> {
+        self.right_pad_with(size, ' ')
+    }
+
+    fn right_pad_with(&self, size: usize, pad_char: char) -> Cow<'_, str> {
+        self.right_pad_with_str(size, pad_char.encode_utf8(&mut [0; 4]))
     }
 
-    fn right(&self, len: usize) -> String {
-        todo!()
+    fn right_pad_with_str(&self, size: usize, pad_str: &str) -> Cow<'_, str> {
+        let s = self.as_ref();
+        let pad_str = if pad_str.is_empty() { SPACE } else { pad_str };
+        let pads = size.saturating_sub(s.chars().count());
+        if pads == 0 {
+            return Cow::Borrowed(s);
+        }
+        Cow::Owned(String::from(s) + &padding(pads, pad_str))
     }
 
-    fn right_pad(&self, size: usize) -> String {
-        todo!()
+    fn right_pad_to_width(&self, width: usize) -> Cow<'_, str> {
+        self.right_pad_to_width_with_str(width, SPACE, PadPolicy::FillWithSpace)
     }
 
-    fn right_pad_with(&self, size: usize, pad_char: char) -> String {
-        todo!()
+    fn right_pad_to_width_with(
+        &self,
+        width: usize,
+        pad_char: char,
+        policy: PadPolicy,
+    ) -> Cow<'_, str> {
+        self.right_pad_to_width_with_str(width, pad_char.encode_utf8(&mut [0; 4]), policy)
     }
 
-    fn right_pad_with_str(&self, size: usize, pad_str: &str) -> String {
-        todo!()
+    fn right_pad_to_width_with_str(
+        &self,
+        width: usize,
+        pad_str: &str,
+        policy: PadPolicy,
+    ) -> Cow<'_, str> {
+        let s = self.as_ref();
+        let pads = width.saturating_sub(s.display_width());
+        if pads == 0 {
+            return Cow::Borrowed(s);
+        }
+        Cow::Owned(String::from(s) + &padding_to_width(pads, pad_str, policy))
     }
 
     fn rotate(&self, shift: isize) -> String {
-        todo!()
+        let s = self.as_ref();
+        rotate_segments(&s.graphemes(true).collect::<Vec<_>>(), shift)
+    }
+
+    fn rotate_code_points(&self, shift: isize) -> String {
+        let s = self.as_ref();
+        let chars: Vec<&str> = s
+            .char_indices()
+            .map(|(i, c)| &s[i..i + c.len_utf8()])
+            .collect();
+        rotate_segments(&chars, shift)
+    }
+
+    fn split_by_chars(&self, separator_chars: Option<&str>) -> Vec<&str> {
+        let s = self.as_ref();
+        split_worker(s, separator_chars, 0, false)
+    }
+
+    fn split_by_chars_max(&self, separator_chars: Option<&str>, max: usize) -> Vec<&str> {
+        let s = self.as_ref();
+        split_worker(s, separator_chars, max, false)
+    }
+
+    fn split_by_character_type(&self) -> Vec<&str> {
+        let s = self.as_ref();
+        split_by_character_type(s, false)
+    }
+
+    fn split_by_character_type_and_camel_case(&self) -> Vec<&str> {
+        let s = self.as_ref();
+        split_by_character_type(s, true)
+    }
+
+    fn split_by_whole_separator(&self, separator: Option<&str>) -> Vec<&str> {
+        let s = self.as_ref();
+        split_by_whole_separator_worker(s, separator, 0)
+    }
+
+    fn split_by_whole_separator_max(&self, separator: Option<&str>, max: usize) -> Vec<&str> {
+        let s = self.as_ref();
+        split_by_whole_separator_worker(s, separator, max)
+    }
+
+    fn split_preserve_all_tokens(&self, separator_chars: Option<&str>) -> Vec<&str> {
+        let s = self.as_ref();
+        split_worker(s, separator_chars, 0, true)
+    }
+
+    fn split_preserve_all_tokens_max(
+        &self,
+        separator_chars: Option<&str>,
+        max: usize,
+    ) -> Vec<&str> {
+        let s = self.as_ref();
+        split_worker(s, separator_chars, max, true)
     }
 
     fn starts_with(&self, prefix: &str, ignore_case: bool) -> bool {
-        todo!()
+        let s = self.as_ref();
+        if ignore_case {
+            starts_with_ignore_case(s, prefix)
+        } else {
+            str::starts_with(s, prefix)
+        }
     }
 
     fn starts_with_any(&self, prefixes: &[&str]) -> bool {
-        todo!()
+        let s = self.as_ref();
+        !str::is_empty(s) && prefixes.iter().any(|prefix| str::starts_with(s, prefix))
+    }
+
+    fn strip(&self) -> &str {
+        self.as_ref().trim_matches(char::is_whitespace)
+    }
+
+    fn strip_in(&self, strip_chars: &str) -> &str {
+        self.as_ref()
+            .trim_matches(|c| str::contains(strip_chars, c))
+    }
+
+    fn strip_end(&self) -> &str {
+        self.as_ref().trim_end_matches(char::is_whitespace)
     }
 
-    fn strip(&self) -> String {
-        todo!()
+    fn strip_end_in(&self, strip_chars: &str) -> &str {
+        self.as_ref()
+            .trim_end_matches(|c| str::contains(strip_chars, c))
     }
 
-    fn strip_in(&self, strip_chars: &str) -> String {
-        todo!()
+    fn strip_start(&self) -> &str {
+        self.as_ref().trim_start_matches(char::is_whitespace)
     }
 
-    fn strip_end(&self) -> String {
-        todo!()
+    fn strip_start_in(&self, strip_chars: &str) -> &str {
+        self.as_ref()
+            .trim_start_matches(|c| str::contains(strip_chars, c))
     }
 
-    fn strip_end_in(&self, strip_chars: &str) -> String {
-        todo!()
+    fn strip_to_empty(&self) -> &str {
+        self.strip()
     }
 
-    fn strip_start(&self) -> String {
-        todo!()
+    fn strip_to_none(&self) -> Option<&str> {
+        Some(self.strip()).filter(|stripped| !stripped.is_blank())
     }
 
-    fn strip_start_in(&self, strip_chars: &str) -> String {
-        todo!()
+    fn substring<R>(&self, range: R) -> &str
+    where
+        R: RangeBounds<usize>,
+    {
+        let s = self.as_ref();
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.saturating_add(1),
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => usize::MAX,
+        };
+        if start >= end {
+            return EMPTY;
+        }
+        let from = byte_index(s, start);
+        let to = from + byte_index(&s[from..], end - start);
+        &s[from..to]
     }
 
-    fn substring(&self, range: Range<usize>) -> String {
-        todo!()
+    fn substring_after(&self, separator: char) -> &str {
+        let s = self.as_ref();
+        match s.find(separator) {
+            Some(pos) => &s[pos + separator.len_utf8()..],
+            None => EMPTY,
+        }
     }
 
-    fn substring_after(&self, separator: char) -> String {
-        todo!()
+    fn substring_after_last(&self, separator: char) -> &str {
+        let s = self.as_ref();
+        match s.rfind(separator) {
+            Some(pos) => &s[pos + separator.len_utf8()..],
+            None => EMPTY,
+        }
     }
 
-    fn substring_after_last(&self, separator: char) -> String {
-        todo!()
+    fn substring_after_last_str(&self, separator: &str) -> &str {
+        let s = self.as_ref();
+        if separator.is_empty() {
+            return EMPTY;
+        }
+        match s.rfind(separator) {
+            Some(pos) => &s[pos + separator.len()..],
+            None => EMPTY,
+        }
     }
 
-    fn substring_after_last_str(&self, separator: &str) -> String {
-        todo!()
+    fn substring_after_str(&self, separator: &str) -> &str {
+        let s = self.as_ref();
+        match s.find(separator) {
+            Some(pos) => &s[pos + separator.len()..],
+            None => EMPTY,
+        }
     }
 
-    fn substring_after_str(&self, separator: &str) -> String {
-        todo!()
+    fn substring_before(&self, separator: char) -> &str {
+        let s = self.as_ref();
+        match s.find(separator) {
+            Some(pos) => &s[..pos],
+            None => s,
+        }
     }
 
-    fn substring_before(&self, separator: char) -> String {
-        todo!()
+    fn substring_before_last(&self, separator: char) -> &str {
+        let s = self.as_ref();
+        match s.rfind(separator) {
+            Some(pos) => &s[..pos],
+            None => s,
+        }
     }
 
-    fn substring_before_last(&self, separator: char) -> String {
-        todo!()
+    fn substring_before_last_str(&self, separator: &str) -> &str {
+        let s = self.as_ref();
+        match s.rfind(separator) {
+            Some(pos) => &s[..pos],
+            None => s,
+        }
     }
 
-    fn substring_before_last_str(&self, separator: &str) -> String {
-        todo!()
+    fn substring_before_str(&self, separator: &str) -> &str {
+        let s = self.as_ref();
+        match s.find(separator) {
+            Some(pos) => &s[..pos],
+            None => s,
+        }
     }
 
-    fn substring_before_str(&self, separator: &str) -> String {
-        todo!()
+    fn substring_between(&self, open: &str, close: &str) -> Option<&str> {
+        let s = self.as_ref();
+        let start = s.find(open)? + open.len();
+        let end = start + s[start..].find(close)?;
+        Some(&s[start..end])
     }
 
-    fn substring_between(&self, open: &str, close: &str) -> String {
-        todo!()
+    fn substring_between_tag(&self, tag: &str) -> Option<&str> {
+        self.substring_between(tag, tag)
     }
 
-    fn substring_between_tag(&self, tag: &str) -> String {
-        todo!()
+    fn substring_between_to_none(&self, open: &str, close: &str) -> Option<&str> {
+        self.substring_between(open, close)
+            .filter(|between| !between.is_blank())
     }
 
     fn swap_case(&self) -> String {
-        todo!()
+        let s = self.as_ref();
+        let mut swapped = String::with_capacity(s.len());
+        for c in s.chars() {
+            if c.is_uppercase() || get_general_category(c) == GeneralCategory::TitlecaseLetter {
+                swapped.extend(c.to_lowercase());
+            } else if c.is_lowercase() {
+                swapped.extend(c.to_uppercase());
+            } else {
+                swapped.push(c);
+            }
+        }
+        swapped
+    }
+
+    fn trim(&self) -> &str {
+        self.as_ref().trim_matches(|c| c <= ' ')
+    }
+
+    fn trim_to_empty(&self) -> &str {
+        StringUtilsExt::trim(self)
     }
 
-    fn trim(&self) -> String {
-        todo!()
+    fn trim_to_none(&self) -> Option<&str> {
+        Some(StringUtilsExt::trim(self)).filter(|trimmed| !trimmed.is_blank())
     }
 
-    fn truncate(&self, max_width: usize) -> String {
-        todo!()
+    fn truncate(&self, max_width: usize) -> &str {
+        self.truncate_with_offset(0, max_width)
     }
 
-    fn truncate_with_offset(&self, offset: usize, max_width: usize) -> String {
-        todo!()
+    fn truncate_with_offset(&self, offset: usize, max_width: usize) -> &str {
+        self.substring(offset..offset.saturating_add(max_width))
     }
 
-    fn uncapitalize(&self) -> String {
-        todo!()
+    fn truncate_to_width(&self, max_width: usize) -> &str {
+        let s = self.as_ref();
+        let mut width = 0;
+        for (start, grapheme) in s.grapheme_indices(true) {
+            width += grapheme.width();
+            if width > max_width {
+                return &s[..start];
+            }
+        }
+        s
+    }
+
+    fn truncate_to_width_with_offset(&self, offset: usize, max_width: usize) -> &str {
+        self.substring(offset..).truncate_to_width(max_width)
+    }
+
+    fn uncapitalize(&self) -> Cow<'_, str> {
+        map_first_char(self.as_ref(), char::to_lowercase)
     }
 
-    fn unwrap_from_char(&self, wrap_char: char) -> String {
-        todo!()
+    fn unwrap_from_char(&self, wrap_char: char) -> &str {
+        let s = self.as_ref();
+        let mut chars = s.chars();
+        match (chars.next(), chars.next_back()) {
+            (Some(first), Some(last))
+                if wrap_char != '\0' && first == wrap_char && last == wrap_char =>
+            {
+                chars.as_str()
+            }
+            _ => s,
+        }
     }
 
-    fn unwrap_from_str(&self, wrap_str: &str) -> String {
-        todo!()
+    fn unwrap_from_str(&self, wrap_str: &str) -> &str {
+        let s = self.as_ref();
+        if str::is_empty(wrap_str) || s.len() < 2 * wrap_str.len() {
+            return s;
+        }
+        s.strip_prefix(wrap_str)
+            .and_then(|rest| rest.strip_suffix(wrap_str))
+            .unwrap_or(s)
+    }
+
+    fn unwrap_quoted(&self, quote: char) -> Cow<'_, str> {
+        let s = self.as_ref();
+        let inner = match s
+            .strip_prefix(quote)
+            .and_then(|rest| rest.strip_suffix(quote))
+        {
+            Some(inner) => inner,
+            None => return Cow::Borrowed(s),
+        };
+        if !str::contains(inner, quote) {
+            return Cow::Borrowed(inner);
+        }
+        let mut unquoted = String::with_capacity(inner.len());
+        let mut chars = inner.chars();
+        while let Some(c) = chars.next() {
+            if c == quote && chars.next() != Some(quote) {
+                return Cow::Borrowed(s);
+            }
+            unquoted.push(c);
+        }
+        Cow::Owned(unquoted)
     }
 
     fn upper_case(&self) -> String {
-        todo!()
+        let s = self.as_ref();
+        str::to_uppercase(s)
     }
 
-    fn wrap(&self, wrap_with: char) -> String {
-        todo!()
+    fn wrap(&self, wrap_with: char) -> Cow<'_, str> {
+        let s = self.as_ref();
+        if str::is_empty(s) || wrap_with == '\0' {
+            return Cow::Borrowed(s);
+        }
+        Cow::Owned(format!("{}{}{}", wrap_with, s, wrap_with))
     }
 
-    fn wrap_if_missing(&self, wrap_with: char) -> String {
-        todo!()
+    fn wrap_if_missing(&self, wrap_with: char) -> Cow<'_, str> {
+        if wrap_with == '\0' {
+            return Cow::Borrowed(self.as_ref());
+        }
+        self.wrap_if_missing_str(wrap_with.encode_utf8(&mut [0; 4]))
     }
 
-    fn wrap_if_missing_str(&self, wrap_with: &str) -> String {
-        todo!()
+    fn wrap_if_missing_str(&self, wrap_with: &str) -> Cow<'_, str> {
+        let s = self.as_ref();
+        if str::is_empty(s) || str::is_empty(wrap_with) {
+            return Cow::Borrowed(s);
+        }
+        let wrap_start = !str::starts_with(s, wrap_with);
+        let wrap_end = !str::ends_with(s, wrap_with);
+        if !wrap_start && !wrap_end {
+            return Cow::Borrowed(s);
+        }
+        let mut wrapped = String::with_capacity(s.len() + 2 * wrap_with.len());
+        if wrap_start {
+            wrapped.push_str(wrap_with);
+        }
+        wrapped.push_str(s);
+        if wrap_end {
+            wrapped.push_str(wrap_with);
+        }
+        Cow::Owned(wrapped)
     }
 
-    fn wrap_with_str(&self, wrap_with: &str) -> String {
-        todo!()
+    fn wrap_with_str(&self, wrap_with: &str) -> Cow<'_, str> {
+        let s = self.as_ref();
+        if str::is_empty(s) || str::is_empty(wrap_with) {
+            return Cow::Borrowed(s);
+        }
+        Cow::Owned(format!("{}{}{}", wrap_with, s, wrap_with))
     }
 }
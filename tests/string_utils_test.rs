@@ -1,7 +1,2686 @@
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use string_utils_rs::error::UtilsError;
+use string_utils_rs::{
+    first_non_blank, first_non_empty, is_all_blank, is_all_empty, is_any_blank, is_any_empty,
+    is_none_blank, is_none_empty, OptionStringUtilsExt, PadPolicy, StringJoiner, StringUtils,
+    StringUtilsExt,
+};
+
 fn assert_abbreviate_with_full_options(
     expected: &str,
     abbrev_marker: &str,
     offset: isize,
     max_width: usize,
 ) {
+    let alphabet = "abcdefghijklmno";
+    let actual = alphabet
+        .abbreviate_with_full_opt(abbrev_marker, offset, max_width)
+        .unwrap();
+    if offset >= 0 && (offset as usize) < alphabet.len() {
+        let offset_char = alphabet.as_bytes()[offset as usize] as char;
+        assert!(
+            actual.contains_char(offset_char),
+            "{:?} should contain the offset char",
+            actual
+        );
+    }
+    assert!(actual.chars().count() <= max_width);
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_index_of() {
+    assert_eq!("".index_of(""), Some(0));
+    assert_eq!("".index_of("a"), None);
+    assert_eq!("aabaabaa".index_of("a"), Some(0));
+    assert_eq!("aabaabaa".index_of("b"), Some(2));
+    assert_eq!("aabaabaa".index_of("ab"), Some(1));
+    assert_eq!("aabaabaa".index_of(""), Some(0));
+    assert_eq!("héllo wörld".index_of("wö"), Some(6));
+    assert_eq!(String::from("héllo").index_of("l"), Some(2));
+}
+
+#[test]
+fn test_index_of_starting_from() {
+    assert_eq!("aabaabaa".index_of_starting_from("a", 0), Some(0));
+    assert_eq!("aabaabaa".index_of_starting_from("b", 0), Some(2));
+    assert_eq!("aabaabaa".index_of_starting_from("ab", 0), Some(1));
+    assert_eq!("aabaabaa".index_of_starting_from("b", 3), Some(5));
+    assert_eq!("aabaabaa".index_of_starting_from("b", 9), None);
+    assert_eq!("aabaabaa".index_of_starting_from("", 2), Some(2));
+    assert_eq!("abc".index_of_starting_from("", 9), Some(3));
+    assert_eq!("ééb".index_of_starting_from("b", 1), Some(2));
+}
+
+#[test]
+fn test_index_of_char() {
+    assert_eq!("".index_of_char('a'), None);
+    assert_eq!("aabaabaa".index_of_char('b'), Some(2));
+    assert_eq!("aabaabaa".index_of_char_starting_from('b', 3), Some(5));
+    assert_eq!("aabaabaa".index_of_char_starting_from('b', 9), None);
+    assert_eq!("日本語".index_of_char('語'), Some(2));
+}
+
+#[test]
+fn test_index_of_ignore_case() {
+    assert_eq!("".index_of_ignore_case(""), Some(0));
+    assert_eq!("aabaabaa".index_of_ignore_case("A"), Some(0));
+    assert_eq!("aabaabaa".index_of_ignore_case("B"), Some(2));
+    assert_eq!("aabaabaa".index_of_ignore_case("AB"), Some(1));
+    assert_eq!(
+        "aabaabaa".index_of_ignore_case_starting_from("B", 3),
+        Some(5)
+    );
+    assert_eq!("aabaabaa".index_of_ignore_case_starting_from("B", 9), None);
+    assert_eq!(
+        "aabaabaa".index_of_ignore_case_starting_from("", 2),
+        Some(2)
+    );
+    assert_eq!("abc".index_of_ignore_case_starting_from("", 9), None);
+    assert_eq!("ÄÖÜäöü".index_of_ignore_case("ÜÄ"), Some(2));
+    assert_eq!("ΑΒΓ".index_of_ignore_case("γ"), Some(2));
+}
+
+#[test]
+fn test_index_of_any() {
+    assert_eq!("".index_of_any(&[""]), Some(0));
+    assert_eq!("".index_of_any(&["a"]), None);
+    assert_eq!("zzabyycdxx".index_of_any(&[]), None);
+    assert_eq!("zzabyycdxx".index_of_any(&["ab", "cd"]), Some(2));
+    assert_eq!("zzabyycdxx".index_of_any(&["cd", "ab"]), Some(2));
+    assert_eq!("zzabyycdxx".index_of_any(&["mn", "op"]), None);
+    assert_eq!("zzabyycdxx".index_of_any(&["zab", "aby"]), Some(1));
+    assert_eq!("zzabyycdxx".index_of_any(&[""]), Some(0));
+
+    assert_eq!("".index_of_any_char(&['a']), None);
+    assert_eq!("zzabyycdxx".index_of_any_char(&['z', 'a']), Some(0));
+    assert_eq!("zzabyycdxx".index_of_any_char(&['b', 'y']), Some(3));
+    assert_eq!("aba".index_of_any_char(&['z']), None);
+    assert_eq!("zzabyycdxx".index_of_any_in("by"), Some(3));
+}
+
+#[test]
+fn test_index_of_any_but() {
+    assert_eq!("".index_of_any_but(&['a']), None);
+    assert_eq!("zzabyycdxx".index_of_any_but(&[]), None);
+    assert_eq!("zzabyycdxx".index_of_any_but(&['z', 'a']), Some(3));
+    assert_eq!("aba".index_of_any_but(&['z']), Some(0));
+    assert_eq!("aba".index_of_any_but(&['a', 'b']), None);
+    assert_eq!("zzabyycdxx".index_of_any_but_in(""), None);
+    assert_eq!("zzabyycdxx".index_of_any_but_in("za"), Some(3));
+    assert_eq!("ééx".index_of_any_but_in("é"), Some(2));
+}
+
+#[test]
+fn test_last_index_of() {
+    assert_eq!("".last_index_of(""), Some(0));
+    assert_eq!("aabaabaa".last_index_of("a"), Some(7));
+    assert_eq!("aabaabaa".last_index_of("b"), Some(5));
+    assert_eq!("aabaabaa".last_index_of("ab"), Some(4));
+    assert_eq!("aabaabaa".last_index_of(""), Some(8));
+    assert_eq!("héllo héllo".last_index_of("é"), Some(7));
+}
+
+#[test]
+fn test_last_index_of_starting_from() {
+    assert_eq!("aabaabaa".last_index_of_starting_from("a", 8), Some(7));
+    assert_eq!("aabaabaa".last_index_of_starting_from("b", 8), Some(5));
+    assert_eq!("aabaabaa".last_index_of_starting_from("ab", 8), Some(4));
+    assert_eq!("aabaabaa".last_index_of_starting_from("b", 9), Some(5));
+    assert_eq!("aabaabaa".last_index_of_starting_from("b", 0), None);
+    assert_eq!("aabaabaa".last_index_of_starting_from("a", 0), Some(0));
+    assert_eq!("aabaabaa".last_index_of_starting_from("ba", 2), Some(2));
+    assert_eq!("ééééb".last_index_of_starting_from("éé", 1), Some(1));
+}
+
+#[test]
+fn test_last_index_of_char() {
+    assert_eq!("".last_index_of_char('a'), None);
+    assert_eq!("aabaabaa".last_index_of_char('b'), Some(5));
+    assert_eq!("aabaabaa".last_index_of_char_starting_from('b', 8), Some(5));
+    assert_eq!("aabaabaa".last_index_of_char_starting_from('b', 4), Some(2));
+    assert_eq!("aabaabaa".last_index_of_char_starting_from('b', 0), None);
+    assert_eq!("aabaabaa".last_index_of_char_starting_from('a', 0), Some(0));
+}
+
+#[test]
+fn test_last_index_of_ignore_case() {
+    assert_eq!("".last_index_of_ignore_case(""), Some(0));
+    assert_eq!("aabaabaa".last_index_of_ignore_case("A"), Some(7));
+    assert_eq!("aabaabaa".last_index_of_ignore_case("B"), Some(5));
+    assert_eq!("aabaabaa".last_index_of_ignore_case("AB"), Some(4));
+    assert_eq!(
+        "aabaabaa".last_index_of_ignore_case_starting_from("A", 8),
+        Some(7)
+    );
+    assert_eq!(
+        "aabaabaa".last_index_of_ignore_case_starting_from("B", 9),
+        Some(5)
+    );
+    assert_eq!(
+        "aabaabaa".last_index_of_ignore_case_starting_from("B", 0),
+        None
+    );
+    assert_eq!(
+        "aabaabaa".last_index_of_ignore_case_starting_from("A", 0),
+        Some(0)
+    );
+    assert_eq!(
+        "aabaabaa".last_index_of_ignore_case_starting_from("AB", 2),
+        Some(1)
+    );
+}
+
+#[test]
+fn test_last_index_of_any() {
+    assert_eq!("zzabyycdxx".last_index_of_any(&[]), None);
+    assert_eq!("zzabyycdxx".last_index_of_any(&["ab", "cd"]), Some(6));
+    assert_eq!("zzabyycdxx".last_index_of_any(&["cd", "ab"]), Some(6));
+    assert_eq!("zzabyycdxx".last_index_of_any(&["mn", "op"]), None);
+    assert_eq!("zzabyycdxx".last_index_of_any(&["mn", ""]), Some(10));
+}
+
+#[test]
+fn test_ordinal_index_of() {
+    assert_eq!("".ordinal_index_of("", 1), Some(0));
+    assert_eq!("aabaabaa".ordinal_index_of("a", 0), None);
+    assert_eq!("aabaabaa".ordinal_index_of("a", 1), Some(0));
+    assert_eq!("aabaabaa".ordinal_index_of("a", 2), Some(1));
+    assert_eq!("aabaabaa".ordinal_index_of("b", 1), Some(2));
+    assert_eq!("aabaabaa".ordinal_index_of("b", 2), Some(5));
+    assert_eq!("aabaabaa".ordinal_index_of("b", 3), None);
+    assert_eq!("aabaabaa".ordinal_index_of("ab", 2), Some(4));
+    assert_eq!("aabaabaa".ordinal_index_of("", 2), Some(0));
+    assert_eq!("aaaa".ordinal_index_of("aa", 2), Some(1));
+}
+
+#[test]
+fn test_last_ordinal_index_of() {
+    assert_eq!("".last_ordinal_index_of("", 1), Some(0));
+    assert_eq!("aabaabaa".last_ordinal_index_of("a", 0), None);
+    assert_eq!("aabaabaa".last_ordinal_index_of("a", 1), Some(7));
+    assert_eq!("aabaabaa".last_ordinal_index_of("a", 2), Some(6));
+    assert_eq!("aabaabaa".last_ordinal_index_of("b", 1), Some(5));
+    assert_eq!("aabaabaa".last_ordinal_index_of("b", 2), Some(2));
+    assert_eq!("aabaabaa".last_ordinal_index_of("ab", 1), Some(4));
+    assert_eq!("aabaabaa".last_ordinal_index_of("ab", 2), Some(1));
+    assert_eq!("aabaabaa".last_ordinal_index_of("", 2), Some(8));
+    assert_eq!("aabaabaa".last_ordinal_index_of("aa", 5), None);
+}
+
+#[test]
+fn test_index_of_difference() {
+    assert_eq!("".index_of_difference(""), None);
+    assert_eq!("".index_of_difference("abc"), Some(0));
+    assert_eq!("abc".index_of_difference(""), Some(0));
+    assert_eq!("abc".index_of_difference("abc"), None);
+    assert_eq!("ab".index_of_difference("abxyz"), Some(2));
+    assert_eq!("abcde".index_of_difference("abxyz"), Some(2));
+    assert_eq!("abcde".index_of_difference("xyz"), Some(0));
+}
+
+#[test]
+fn test_contains() {
+    assert!(StringUtilsExt::contains(&"", ""));
+    assert!(StringUtilsExt::contains(&"abc", ""));
+    assert!(StringUtilsExt::contains(&"abc", "a"));
+    assert!(!StringUtilsExt::contains(&"abc", "z"));
+    assert!(StringUtilsExt::contains(&String::from("héllo"), "él"));
+    assert!("abc".contains_char('b'));
+    assert!(!"".contains_char('b'));
+}
+
+#[test]
+fn test_contains_ignore_case() {
+    assert!("".contains_ignore_case(""));
+    assert!("abc".contains_ignore_case("A"));
+    assert!("abc".contains_ignore_case("bC"));
+    assert!(!"abc".contains_ignore_case("Z"));
+    assert!("ΑΒΓ".contains_ignore_case("βγ"));
+    assert!("Straße".contains_ignore_case("STRAẞE"));
+    assert!("ПРИВЕТ".contains_ignore_case("вет"));
+}
+
+#[test]
+fn test_contains_any() {
+    assert!(!"".contains_any(&[""]));
+    assert!(!"abc".contains_any(&[]));
+    assert!("abcd".contains_any(&["ab", "xyz"]));
+    assert!("abcd".contains_any(&["xyz", "cd"]));
+    assert!(!"abc".contains_any(&["xyz", "ABC"]));
+    assert!("abc".contains_any(&[""]));
+
+    assert!(!"".contains_any_char(&['a']));
+    assert!("zzabyycdxx".contains_any_char(&['z', 'a']));
+    assert!(!"aba".contains_any_char(&['z']));
+    assert!("zzabyycdxx".contains_any_in("by"));
+    assert!(!"aba".contains_any_in(""));
+}
+
+#[test]
+fn test_contains_any_ignore_case() {
+    assert!(!"".contains_any_ignore_case(&["a"]));
+    assert!("abc".contains_any_ignore_case(&["xyz", "ABC"]));
+    assert!("ÉTÉ".contains_any_ignore_case(&["été"]));
+    assert!(!"abc".contains_any_ignore_case(&["xyz"]));
+}
+
+#[test]
+fn test_contains_any_with() {
+    let fold = |s: &str| -> String {
+        s.chars()
+            .map(|c| match c {
+                'é' | 'è' | 'ê' => 'e',
+                _ => c,
+            })
+            .collect()
+    };
+    let accent_insensitive = |s: &str, search: &str| fold(s).contains(&fold(search));
+    assert!("crème brûlée".contains_any_with(&["creme"], accent_insensitive));
+    assert!(!"crème brûlée".contains_any_with(&["cream"], accent_insensitive));
+    assert!(!"".contains_any_with(&[""], |_, _| true));
+    assert!("abc".contains_any_with(&["ABC"], |s, search| s.eq_ignore_ascii_case(search)));
+}
+
+#[test]
+fn test_contains_none() {
+    assert!("".contains_none(&['a']));
+    assert!("ab".contains_none(&[]));
+    assert!("abab".contains_none(&['x', 'y', 'z']));
+    assert!(!"ab1".contains_none(&['x', 'y', '1']));
+    assert!(!"abz".contains_none(&['x', 'y', 'z']));
+    assert!("abab".contains_none_in("xyz"));
+    assert!(!"héllo".contains_none_in("é"));
+}
+
+#[test]
+fn test_contains_only() {
+    assert!("".contains_only(&[]));
+    assert!(!"ab".contains_only(&[]));
+    assert!("abab".contains_only(&['a', 'b', 'c']));
+    assert!(!"ab1".contains_only(&['a', 'b', 'c']));
+    assert!(!"abz".contains_only(&['a', 'b', 'c']));
+    assert!("abab".contains_only_in("abc"));
+    assert!("日本日".contains_only_in("日本"));
+}
+
+#[test]
+fn test_contains_whitespace() {
+    assert!(!"".contains_whitespace());
+    assert!(!"ab".contains_whitespace());
+    assert!("a b".contains_whitespace());
+    assert!("a\tb".contains_whitespace());
+    assert!("a\u{3000}b".contains_whitespace());
+    assert!("a\u{a0}b".contains_whitespace());
+    assert!(!"a\u{200b}b".contains_whitespace());
+}
+
+#[test]
+fn test_left_pad() {
+    assert_eq!("".left_pad(3), "   ");
+    assert_eq!("bat".left_pad(3), "bat");
+    assert_eq!("bat".left_pad(5), "  bat");
+    assert_eq!("bat".left_pad(1), "bat");
+    assert_eq!("bat".left_pad_with(5, 'z'), "zzbat");
+    assert_eq!("bat".left_pad_with(5, 'é'), "éébat");
+    assert_eq!("日本".left_pad_with(4, '*'), "**日本");
+}
+
+#[test]
+fn test_left_pad_with_str() {
+    assert_eq!("".left_pad_with_str(3, "z"), "zzz");
+    assert_eq!("bat".left_pad_with_str(3, "yz"), "bat");
+    assert_eq!("bat".left_pad_with_str(5, "yz"), "yzbat");
+    assert_eq!("bat".left_pad_with_str(8, "yz"), "yzyzybat");
+    assert_eq!("bat".left_pad_with_str(1, "yz"), "bat");
+    assert_eq!("bat".left_pad_with_str(4, "yz"), "ybat");
+    assert_eq!("bat".left_pad_with_str(5, ""), "  bat");
+    assert_eq!("bat".left_pad_with_str(8, "日本語"), "日本語日本bat");
+}
+
+#[test]
+fn test_right_pad() {
+    assert_eq!("".right_pad(3), "   ");
+    assert_eq!("bat".right_pad(3), "bat");
+    assert_eq!("bat".right_pad(5), "bat  ");
+    assert_eq!("bat".right_pad_with(5, 'z'), "batzz");
+    assert_eq!("bat".right_pad_with_str(8, "yz"), "batyzyzy");
+    assert_eq!("bat".right_pad_with_str(5, ""), "bat  ");
+    assert_eq!("bat".right_pad_with_str(7, "éß"), "batéßéß");
+}
+
+#[test]
+fn test_pad_above_pad_limit() {
+    let padded = "bat".left_pad_with(10_003, 'é');
+    assert_eq!(padded.chars().count(), 10_003);
+    assert!(padded.ends_with("ébat"));
+    assert!(padded.chars().take(10_000).all(|c| c == 'é'));
+
+    let padded = "bat".right_pad_with_str(10_004, "xyz");
+    assert_eq!(padded.len(), 10_004);
+    assert!(str::starts_with(&padded, "batxyzxyz"));
+    assert!(padded.ends_with("xyzxy"));
+}
+
+#[test]
+fn test_center() {
+    assert_eq!("".center(4), "    ");
+    assert_eq!("ab".center(0), "ab");
+    assert_eq!("ab".center(4), " ab ");
+    assert_eq!("abcd".center(2), "abcd");
+    assert_eq!("a".center(4), " a  ");
+    assert_eq!("a".center_with_char(4, 'y'), "yayy");
+    assert_eq!("a".center_with(4, "yz"), "yayz");
+    assert_eq!("abc".center_with(7, ""), "  abc  ");
+    assert_eq!("é".center_with_char(3, '日'), "日é日");
+}
+
+#[test]
+fn test_strip() {
+    assert_eq!("".strip(), "");
+    assert_eq!("   ".strip(), "");
+    assert_eq!("abc".strip(), "abc");
+    assert_eq!("  abc".strip(), "abc");
+    assert_eq!("abc  ".strip(), "abc");
+    assert_eq!(" abc ".strip(), "abc");
+    assert_eq!(" ab c ".strip(), "ab c");
+    assert_eq!("\u{3000}\u{a0}abc\u{2003}\n".strip(), "abc");
+    assert_eq!(" abc ".strip_start(), "abc ");
+    assert_eq!(" abc ".strip_end(), " abc");
+}
+
+#[test]
+fn test_strip_in() {
+    assert_eq!("".strip_in("xyz"), "");
+    assert_eq!("abc".strip_in("xyz"), "abc");
+    assert_eq!("  abc".strip_in(""), "  abc");
+    assert_eq!("  abcyx".strip_in("xyz"), "  abc");
+    assert_eq!("yxabcxy".strip_in("xyz"), "abc");
+    assert_eq!("yxabc  ".strip_start_in("xyz"), "abc  ");
+    assert_eq!("abc  ".strip_start_in(""), "abc  ");
+    assert_eq!("120.00".strip_end_in(".0"), "12");
+    assert_eq!("ééabcé".strip_in("é"), "abc");
+}
+
+#[test]
+fn test_trim() {
+    assert_eq!(StringUtilsExt::trim(&""), "");
+    assert_eq!(StringUtilsExt::trim(&"     "), "");
+    assert_eq!(StringUtilsExt::trim(&"abc"), "abc");
+    assert_eq!(StringUtilsExt::trim(&"    abc    "), "abc");
+    assert_eq!(StringUtilsExt::trim(&"\u{0}\t\r\n\u{1f}abc\u{7}"), "abc");
+    assert_eq!(
+        StringUtilsExt::trim(&"\u{3000}abc\u{a0}"),
+        "\u{3000}abc\u{a0}"
+    );
+}
+
+#[test]
+fn test_chomp() {
+    assert_eq!("".chomp(), "");
+    assert_eq!("abc \r".chomp(), "abc ");
+    assert_eq!("abc\n".chomp(), "abc");
+    assert_eq!("abc\r\n".chomp(), "abc");
+    assert_eq!("abc\r\n\r\n".chomp(), "abc\r\n");
+    assert_eq!("abc\n\r".chomp(), "abc\n");
+    assert_eq!("abc\n\rabc".chomp(), "abc\n\rabc");
+    assert_eq!("\r".chomp(), "");
+    assert_eq!("\n".chomp(), "");
+    assert_eq!("\r\n".chomp(), "");
+    assert_eq!("foobar".chomp_specified("bar"), "foo");
+    assert_eq!("foobar".chomp_specified("baz"), "foobar");
+    assert_eq!("foo".chomp_specified("foooo"), "foo");
+    assert_eq!("foo ".chomp_specified(""), "foo ");
+}
+
+#[test]
+fn test_chop() {
+    assert_eq!("".chop(), "");
+    assert_eq!("abc \r".chop(), "abc ");
+    assert_eq!("abc\n".chop(), "abc");
+    assert_eq!("abc\r\n".chop(), "abc");
+    assert_eq!("abc".chop(), "ab");
+    assert_eq!("abc\nabc".chop(), "abc\nab");
+    assert_eq!("a".chop(), "");
+    assert_eq!("\r".chop(), "");
+    assert_eq!("\n".chop(), "");
+    assert_eq!("\r\n".chop(), "");
+    assert_eq!("\n\r".chop(), "\n");
+    assert_eq!("héllö".chop(), "héll");
+}
+
+#[test]
+fn test_substring() {
+    assert_eq!("".substring(0..1), "");
+    assert_eq!("abc".substring(0..2), "ab");
+    let (start, end) = (2, 0);
+    assert_eq!("abc".substring(start..end), "");
+    assert_eq!("abc".substring(2..4), "c");
+    assert_eq!("abc".substring(4..6), "");
+    assert_eq!("abc".substring(2..2), "");
+    assert_eq!("abc".substring(1..), "bc");
+    assert_eq!("abc".substring(..=1), "ab");
+    assert_eq!("abc".substring(..), "abc");
+    assert_eq!("héllo wörld".substring(1..8), "éllo wö");
+    assert_eq!("日本語".substring(1..usize::MAX), "本語");
+}
+
+#[test]
+fn test_left_right_mid() {
+    assert_eq!("".left(0), "");
+    assert_eq!("abc".left(0), "");
+    assert_eq!("abc".left(2), "ab");
+    assert_eq!("abc".left(4), "abc");
+    assert_eq!("abc".right(0), "");
+    assert_eq!("abc".right(2), "bc");
+    assert_eq!("abc".right(4), "abc");
+    assert_eq!("abc".mid(0, 2), "ab");
+    assert_eq!("abc".mid(0, 4), "abc");
+    assert_eq!("abc".mid(2, 4), "c");
+    assert_eq!("abc".mid(4, 2), "");
+    assert_eq!("héllö".left(2), "hé");
+    assert_eq!("héllö".right(2), "lö");
+    assert_eq!("héllö".mid(1, usize::MAX), "éllö");
+}
+
+#[test]
+fn test_substring_after() {
+    assert_eq!("".substring_after('a'), "");
+    assert_eq!("abc".substring_after('a'), "bc");
+    assert_eq!("abcba".substring_after('b'), "cba");
+    assert_eq!("abc".substring_after('c'), "");
+    assert_eq!("abc".substring_after('d'), "");
+    assert_eq!("a→b→c".substring_after('→'), "b→c");
+
+    assert_eq!("".substring_after_str("a"), "");
+    assert_eq!("abc".substring_after_str("b"), "c");
+    assert_eq!("abcba".substring_after_str("b"), "cba");
+    assert_eq!("abc".substring_after_str("d"), "");
+    assert_eq!("abc".substring_after_str(""), "abc");
+}
+
+#[test]
+fn test_substring_after_last() {
+    assert_eq!("".substring_after_last('a'), "");
+    assert_eq!("abc".substring_after_last('a'), "bc");
+    assert_eq!("abcba".substring_after_last('b'), "a");
+    assert_eq!("abc".substring_after_last('c'), "");
+    assert_eq!("a".substring_after_last('z'), "");
+    assert_eq!("a→b→c".substring_after_last('→'), "c");
+
+    assert_eq!("abc".substring_after_last_str("a"), "bc");
+    assert_eq!("abcba".substring_after_last_str("b"), "a");
+    assert_eq!("abc".substring_after_last_str("c"), "");
+    assert_eq!("a".substring_after_last_str("z"), "");
+    assert_eq!("a".substring_after_last_str(""), "");
+}
+
+#[test]
+fn test_substring_before() {
+    assert_eq!("".substring_before('a'), "");
+    assert_eq!("abc".substring_before('a'), "");
+    assert_eq!("abcba".substring_before('b'), "a");
+    assert_eq!("abc".substring_before('c'), "ab");
+    assert_eq!("abc".substring_before('d'), "abc");
+    assert_eq!("日本→語".substring_before('→'), "日本");
+
+    assert_eq!("abc".substring_before_str("a"), "");
+    assert_eq!("abcba".substring_before_str("b"), "a");
+    assert_eq!("abc".substring_before_str("c"), "ab");
+    assert_eq!("abc".substring_before_str("d"), "abc");
+    assert_eq!("abc".substring_before_str(""), "");
+}
+
+#[test]
+fn test_substring_before_last() {
+    assert_eq!("".substring_before_last('a'), "");
+    assert_eq!("abcba".substring_before_last('b'), "abc");
+    assert_eq!("abc".substring_before_last('c'), "ab");
+    assert_eq!("a".substring_before_last('a'), "");
+    assert_eq!("a".substring_before_last('z'), "a");
+
+    assert_eq!("abcba".substring_before_last_str("b"), "abc");
+    assert_eq!("abc".substring_before_last_str("c"), "ab");
+    assert_eq!("a".substring_before_last_str("a"), "");
+    assert_eq!("a".substring_before_last_str("z"), "a");
+    assert_eq!("a".substring_before_last_str(""), "a");
+}
+
+#[test]
+fn test_substring_between() {
+    assert_eq!("wx[b]yz".substring_between("[", "]"), Some("b"));
+    assert_eq!("".substring_between("", ""), Some(""));
+    assert_eq!("".substring_between("", "]"), None);
+    assert_eq!("".substring_between("[", "]"), None);
+    assert_eq!("yabcz".substring_between("", ""), Some(""));
+    assert_eq!("yabcz".substring_between("y", "z"), Some("abc"));
+    assert_eq!("yabczyabcz".substring_between("y", "z"), Some("abc"));
+    assert_eq!("«ünïcödé»".substring_between("«", "»"), Some("ünïcödé"));
+
+    assert_eq!("tagabctag".substring_between_tag(""), Some(""));
+    assert_eq!("tagabctag".substring_between_tag("tag"), Some("abc"));
+    assert_eq!("abc".substring_between_tag("tag"), None);
+}
+
+#[test]
+fn test_abbreviate() {
+    assert_eq!("".abbreviate(4).unwrap(), "");
+    assert_eq!("abcdefg".abbreviate(6).unwrap(), "abc...");
+    assert_eq!("abcdefg".abbreviate(7).unwrap(), "abcdefg");
+    assert_eq!("abcdefg".abbreviate(8).unwrap(), "abcdefg");
+    assert_eq!("abcdefg".abbreviate(4).unwrap(), "a...");
+    assert!("abcdefg".abbreviate(3).is_err());
+    assert_eq!("héllo wörld".abbreviate(8).unwrap(), "héllo...");
+    assert_eq!("héllo wörld".abbreviate(11).unwrap(), "héllo wörld");
+    assert_eq!("日本語のテキスト".abbreviate(5).unwrap(), "日本...");
+}
+
+#[test]
+fn test_abbreviate_with_abbrev_marker() {
+    assert_eq!(
+        "abcdefg".abbreviate_with_abbrev_marker("", 4).unwrap(),
+        "abcd"
+    );
+    assert_eq!(
+        "abcdefg".abbreviate_with_abbrev_marker(".", 5).unwrap(),
+        "abcd."
+    );
+    assert_eq!(
+        "abcdefg".abbreviate_with_abbrev_marker("--", 7).unwrap(),
+        "abcdefg"
+    );
+    assert!("abcdefg".abbreviate_with_abbrev_marker("--", 2).is_err());
+    assert_eq!(
+        "héllo wörld".abbreviate_with_abbrev_marker("…", 6).unwrap(),
+        "héllo…"
+    );
+    assert_eq!(
+        "héllo wörld".abbreviate_with_abbrev_marker("", 7).unwrap(),
+        "héllo w"
+    );
+    assert_eq!(
+        "héllo".abbreviate_with_abbrev_marker("……", 5).unwrap(),
+        "héllo"
+    );
+    assert!("héllo wörld"
+        .abbreviate_with_abbrev_marker("……", 2)
+        .is_err());
+}
+
+#[test]
+fn test_abbreviate_with_full_opt() {
+    assert_abbreviate_with_full_options("abcdefg---", "---", -1, 10);
+    assert_abbreviate_with_full_options("abcdefg---", "---", 0, 10);
+    assert_abbreviate_with_full_options("abcdefg---", "---", 1, 10);
+    assert_abbreviate_with_full_options("abcdefg---", "---", 4, 10);
+    assert_abbreviate_with_full_options("__fghijk__", "__", 5, 10);
+    assert_abbreviate_with_full_options("__ghijk__", "__", 6, 9);
+    assert_abbreviate_with_full_options("__hijk__", "__", 7, 8);
+    assert_abbreviate_with_full_options("__ijklmno", "__", 8, 9);
+    assert_abbreviate_with_full_options("__ijklmno", "__", 9, 9);
+    assert_abbreviate_with_full_options("__ijklmno", "__", 12, 9);
+    assert_abbreviate_with_full_options("__ijklmno", "__", 100, 9);
+    assert_abbreviate_with_full_options("abcdefghijklmno", "-", 5, 15);
+    assert!("abcdefghij".abbreviate_with_full_opt("::", 5, 4).is_err());
+    assert_eq!(
+        "héllo wörld, héllo"
+            .abbreviate_with_full_opt("…", 6, 8)
+            .unwrap(),
+        "…wörld,…"
+    );
+    assert_eq!(
+        "héllo wörld, héllo"
+            .abbreviate_with_full_opt("…", 15, 8)
+            .unwrap(),
+        "…, héllo"
+    );
+    assert_eq!(
+        "日本語のテキストです"
+            .abbreviate_with_full_opt("..", 5, 8)
+            .unwrap(),
+        "..テキストです"
+    );
+}
+
+#[test]
+fn test_abbreviate_with_offset() {
+    assert_eq!("".abbreviate_with_offset(0, 10).unwrap(), "");
+    assert_eq!(
+        "abcdefghijklmno".abbreviate_with_offset(-1, 10).unwrap(),
+        "abcdefg..."
+    );
+    assert_eq!(
+        "abcdefghijklmn"
+            .abbreviate_with_offset(isize::MIN + 5, 6)
+            .unwrap(),
+        "abc..."
+    );
+    assert_eq!(
+        "abcdefghijklmn"
+            .abbreviate_with_offset(isize::MAX, 7)
+            .unwrap(),
+        "...klmn"
+    );
+    assert_eq!(
+        "abcdefghijklmno".abbreviate_with_offset(5, 10).unwrap(),
+        "...fghi..."
+    );
+    assert_eq!(
+        "abcdefghijklmno".abbreviate_with_offset(10, 10).unwrap(),
+        "...ijklmno"
+    );
+    assert!("abcdefghij".abbreviate_with_offset(0, 3).is_err());
+    assert!("abcdefghij".abbreviate_with_offset(5, 6).is_err());
+    assert_eq!(
+        "héllo wörld".abbreviate_with_offset(0, 8).unwrap(),
+        "héllo..."
+    );
+    assert_eq!(
+        "héllo wörld".abbreviate_with_offset(9, 8).unwrap(),
+        "...wörld"
+    );
+    assert_eq!(
+        String::from("héllo wörld")
+            .abbreviate_with_offset(9, 8)
+            .unwrap(),
+        "...wörld"
+    );
+}
+
+#[test]
+fn test_abbreviate_middle() {
+    assert_eq!("".abbreviate_middle(".", 5), "");
+    assert_eq!("abc".abbreviate_middle("", 2), "abc");
+    assert_eq!("abc".abbreviate_middle(".", 3), "abc");
+    assert_eq!("abc".abbreviate_middle(".", 2), "abc");
+    assert_eq!("abcdef".abbreviate_middle(".", 4), "ab.f");
+    assert_eq!("abcdefghij".abbreviate_middle("...", 7), "ab...ij");
+    assert_eq!("héllo wörld".abbreviate_middle("…", 5), "hé…ld");
+    assert_eq!("日本語のテキスト".abbreviate_middle("..", 6), "日本..スト");
+    assert_eq!(String::from("abcdef").abbreviate_middle(".", 4), "ab.f");
+}
+
+#[test]
+fn test_truncate() {
+    assert_eq!("".truncate(4), "");
+    assert_eq!("abcdefg".truncate(0), "");
+    assert_eq!("abcdefg".truncate(4), "abcd");
+    assert_eq!("abcdefg".truncate(7), "abcdefg");
+    assert_eq!("abcdefg".truncate(8), "abcdefg");
+    assert_eq!("héllo wörld".truncate(7), "héllo w");
+    assert_eq!("日本語".truncate(2), "日本");
+    assert_eq!(String::from("héllo").truncate(2), "hé");
+}
+
+#[test]
+fn test_truncate_with_offset() {
+    assert_eq!("".truncate_with_offset(0, 4), "");
+    assert_eq!("abcdefghijklmno".truncate_with_offset(0, 10), "abcdefghij");
+    assert_eq!("abcdefghijklmno".truncate_with_offset(5, 10), "fghijklmno");
+    assert_eq!("abcdefghijklmno".truncate_with_offset(5, 5), "fghij");
+    assert_eq!("abcdefghijklmno".truncate_with_offset(15, 5), "");
+    assert_eq!("abcdefghijklmno".truncate_with_offset(20, 5), "");
+    assert_eq!(
+        "abcdefghijklmno".truncate_with_offset(3, usize::MAX),
+        "defghijklmno"
+    );
+    assert_eq!("héllo wörld".truncate_with_offset(6, 3), "wör");
+    assert_eq!("héllo wörld".truncate_with_offset(1, 1), "é");
+    assert_eq!(
+        String::from("héllo wörld").truncate_with_offset(6, 3),
+        "wör"
+    );
+}
+
+#[test]
+fn test_remove() {
+    assert_eq!("".remove("a"), "");
+    assert_eq!("queued".remove(""), "queued");
+    assert_eq!("queued".remove("ue"), "qd");
+    assert_eq!("queued".remove("zz"), "queued");
+    assert_eq!("héllo héllo".remove("é"), "hllo hllo");
+    assert_eq!("queued".remove_all("ue"), "qd");
+    assert_eq!("queued".remove_char('u'), "qeed");
+    assert_eq!("queued".remove_char('z'), "queued");
+    assert_eq!("queued".remove_first("ue"), "qued");
+    assert_eq!("queued".remove_first(""), "queued");
+    assert_eq!(String::from("queued").remove("ue"), "qd");
+}
+
+#[test]
+fn test_remove_ignore_case() {
+    assert_eq!("".remove_ignore_case("a"), "");
+    assert_eq!("queued".remove_ignore_case(""), "queued");
+    assert_eq!("queued".remove_ignore_case("ue"), "qd");
+    assert_eq!("queued".remove_ignore_case("UE"), "qd");
+    assert_eq!("quEUed".remove_ignore_case("Ue"), "qd");
+    assert_eq!("queued".remove_ignore_case("zZ"), "queued");
+    assert_eq!("ÉtÉ été".remove_ignore_case("é"), "t t");
+}
+
+#[test]
+fn test_remove_start() {
+    assert_eq!("".remove_start("a"), "");
+    assert_eq!("www.domain.com".remove_start("www."), "domain.com");
+    assert_eq!("domain.com".remove_start("www."), "domain.com");
+    assert_eq!("www.domain.com".remove_start("domain"), "www.domain.com");
+    assert_eq!("abc".remove_start(""), "abc");
+    assert_eq!("/path".remove_start_char('/'), "path");
+    assert_eq!("path".remove_start_char('/'), "path");
+    assert_eq!(
+        "WWW.domain.com".remove_start_ignore_case("www."),
+        "domain.com"
+    );
+    assert_eq!("ÄBC".remove_start_ignore_case("äb"), "C");
+    assert_eq!("abc".remove_start_ignore_case(""), "abc");
+}
+
+#[test]
+fn test_remove_end() {
+    assert_eq!("".remove_end("a"), "");
+    assert_eq!("www.domain.com".remove_end(".com."), "www.domain.com");
+    assert_eq!("www.domain.com".remove_end(".com"), "www.domain");
+    assert_eq!("www.domain.com".remove_end("domain"), "www.domain.com");
+    assert_eq!("abc".remove_end(""), "abc");
+    assert_eq!(
+        "www.domain.COM".remove_end_ignore_case(".com"),
+        "www.domain"
+    );
+    assert_eq!("abcÖ".remove_end_ignore_case("cö"), "ab");
+    assert_eq!("abc".remove_end_ignore_case("xabc"), "abc");
+}
+
+#[test]
+fn test_remove_does_not_allocate_without_match() {
+    assert!(matches!("queued".remove("zz"), Cow::Borrowed("queued")));
+    assert!(matches!("queued".remove_char('z'), Cow::Borrowed("queued")));
+    assert!(matches!(
+        "queued".remove_first("zz"),
+        Cow::Borrowed("queued")
+    ));
+    assert!(matches!(
+        "queued".remove_ignore_case("zz"),
+        Cow::Borrowed("queued")
+    ));
+    let text = String::from("abc");
+    let borrowed: &str = text.remove_start("a");
+    assert_eq!(borrowed, "bc");
+    assert_eq!(text.remove_end("c"), "ab");
+    assert_eq!("ABC".remove_start_ignore_case("a"), "BC");
+    assert!(matches!(
+        String::from("abc").remove("z"),
+        Cow::Borrowed("abc")
+    ));
+}
+
+#[test]
+fn test_remove_checked() {
+    assert_eq!("queued".remove_checked("ue").as_deref(), Some("qd"));
+    assert_eq!("queued".remove_checked("zz"), None);
+    assert_eq!("queued".remove_checked(""), None);
+    assert_eq!("queued".remove_char_checked('z'), None);
+    assert_eq!("queued".remove_first_checked("ue").as_deref(), Some("qued"));
+    assert_eq!(
+        "queued".remove_ignore_case_checked("UE").as_deref(),
+        Some("qd")
+    );
+    assert_eq!("queued".remove_ignore_case_checked("ZZ"), None);
+    assert_eq!("www.a.com".remove_start_checked("www."), Some("a.com"));
+    assert_eq!("a.com".remove_start_checked("www."), None);
+    assert_eq!("a.com".remove_start_checked(""), None);
+    assert_eq!("/a".remove_start_char_checked('/'), Some("a"));
+    assert_eq!("a.com".remove_end_checked(".com"), Some("a"));
+    assert_eq!("a.com".remove_end_checked(".org"), None);
+    assert_eq!("A.COM".remove_start_ignore_case_checked("a."), Some("COM"));
+    assert_eq!("A.COM".remove_end_ignore_case_checked(".com"), Some("A"));
+    assert_eq!("A.COM".remove_end_ignore_case_checked(".org"), None);
+}
+
+#[test]
+fn test_replace_each() {
+    assert_eq!("".replace_each(&["a"], &["b"]).unwrap(), "");
+    assert_eq!("aba".replace_each(&[], &[]).unwrap(), "aba");
+    assert_eq!("aba".replace_each(&["a"], &[]).unwrap(), "aba");
+    assert_eq!("aba".replace_each(&["a"], &[""]).unwrap(), "b");
+    assert_eq!("aba".replace_each(&[""], &["a"]).unwrap(), "aba");
+    assert_eq!(
+        "abcde".replace_each(&["ab", "d"], &["w", "t"]).unwrap(),
+        "wcte"
+    );
+    assert_eq!(
+        "abcde".replace_each(&["ab", "d"], &["d", "t"]).unwrap(),
+        "dcte"
+    );
+    assert_eq!(
+        "abc".replace_each(&["a", "ab"], &["1", "2"]).unwrap(),
+        "1bc"
+    );
+    assert_eq!("abc".replace_each(&["ab", "a"], &["2", "1"]).unwrap(), "2c");
+    assert_eq!("aaa".replace_each(&["aa"], &["b"]).unwrap(), "ba");
+    assert_eq!(
+        "héllo wörld"
+            .replace_each(&["é", "ö"], &["e", "o"])
+            .unwrap(),
+        "hello world"
+    );
+    assert!(matches!(
+        "abc".replace_each(&["a", "b"], &["x"]),
+        Err(UtilsError::InvalidArgument(_))
+    ));
+}
+
+#[test]
+fn test_replace_each_repeatedly() {
+    assert_eq!(
+        "".replace_each_repeatedly(&["a"], &["b"], None).unwrap(),
+        ""
+    );
+    assert_eq!(
+        "aba".replace_each_repeatedly(&["a"], &[""], None).unwrap(),
+        "b"
+    );
+    assert_eq!(
+        "abcde"
+            .replace_each_repeatedly(&["ab", "d"], &["w", "t"], None)
+            .unwrap(),
+        "wcte"
+    );
+    assert_eq!(
+        "abcde"
+            .replace_each_repeatedly(&["ab", "d"], &["d", "t"], None)
+            .unwrap(),
+        "tcte"
+    );
+    assert_eq!(
+        "a".replace_each_repeatedly(&["a", "b", "c"], &["b", "c", "d"], None)
+            .unwrap(),
+        "d"
+    );
+    assert_eq!(
+        "a".replace_each_repeatedly(&["a", "b", "c"], &["b", "c", "d"], Some(2))
+            .unwrap(),
+        "c"
+    );
+    assert_eq!(
+        "xyz"
+            .replace_each_repeatedly(&["a", "b"], &["b", "a"], None)
+            .unwrap(),
+        "xyz"
+    );
+}
+
+#[test]
+fn test_replace_each_repeatedly_cycle() {
+    match "abcde".replace_each_repeatedly(&["ab", "d"], &["d", "ab"], None) {
+        Err(UtilsError::ReplacementCycle(cycle)) => assert_eq!(cycle, ["ab", "d", "ab"]),
+        other => panic!("expected a replacement cycle, got {:?}", other),
+    }
+    match "a".replace_each_repeatedly(&["a", "b"], &["b", "a"], Some(10)) {
+        Err(err) => assert_eq!(err.to_string(), "Replacement cycle detected: a -> b -> a"),
+        other => panic!("expected a replacement cycle, got {:?}", other),
+    }
+    match "a".replace_each_repeatedly(&["a"], &["aa"], None) {
+        Err(UtilsError::ReplacementCycle(cycle)) => assert_eq!(cycle, ["a", "a"]),
+        other => panic!("expected a replacement cycle, got {:?}", other),
+    }
+    // The cycle goes through the text around the replacements.
+    match "xb".replace_each_repeatedly(&["xa", "b"], &["xb", "a"], None) {
+        Err(UtilsError::ReplacementCycle(cycle)) => assert_eq!(cycle, ["b", "xa", "b"]),
+        other => panic!("expected a replacement cycle, got {:?}", other),
+    }
+    // A growing cycle is caught on the second pass instead of running all of them.
+    match "a".replace_each_repeatedly(&["a"], &["aa"], Some(usize::MAX)) {
+        Err(UtilsError::ReplacementCycle(cycle)) => assert_eq!(cycle, ["a", "a"]),
+        other => panic!("expected a replacement cycle, got {:?}", other),
+    }
+    match "ab".replace_each_repeatedly(&["a", "b"], &["b", "ab"], Some(1000)) {
+        Err(UtilsError::ReplacementCycle(cycle)) => assert_eq!(cycle, ["a", "b", "a"]),
+        other => panic!("expected a replacement cycle, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_replace_each_repeatedly_unreached_cycle() {
+    // "a" <-> "b" is a cycle, but the text never gets there.
+    let search = ["a", "b", "c", "d", "e"];
+    let replace = ["b", "a", "d", "e", "f"];
+    assert_eq!(
+        "c".replace_each_repeatedly(&search, &replace, Some(1))
+            .unwrap(),
+        "d"
+    );
+    assert_eq!(
+        "c".replace_each_repeatedly(&search, &replace, None)
+            .unwrap(),
+        "f"
+    );
+}
+
+#[test]
+fn test_replace_each_repeatedly_out_of_passes() {
+    // By default there is one more pass than search strings, and running out of them without
+    // a cycle returns the text after the last one.
+    assert_eq!(
+        "aab"
+            .replace_each_repeatedly(&["ab"], &["b"], None)
+            .unwrap(),
+        "b"
+    );
+    assert_eq!(
+        "aaab"
+            .replace_each_repeatedly(&["ab"], &["b"], None)
+            .unwrap(),
+        "ab"
+    );
+    assert_eq!(
+        "aab"
+            .replace_each_repeatedly(&["ab"], &["b"], Some(1))
+            .unwrap(),
+        "ab"
+    );
+}
+
+#[test]
+fn test_is_alpha() {
+    assert!(!"".is_alpha());
+    assert!(!"  ".is_alpha());
+    assert!("abc".is_alpha());
+    assert!(!"ab2c".is_alpha());
+    assert!(!"ab-c".is_alpha());
+    assert!("héllo".is_alpha());
+    assert!("日本語".is_alpha());
+    assert!("ǅemal".is_alpha());
+    assert!(!"Ⅻ".is_alpha());
+    assert!(!"e\u{301}".is_alpha());
+
+    assert!("".is_alpha_space());
+    assert!("  ".is_alpha_space());
+    assert!("ab c".is_alpha_space());
+    assert!(!"ab2c".is_alpha_space());
+    assert!(!"ab\tc".is_alpha_space());
+}
+
+#[test]
+fn test_is_alphanumeric() {
+    assert!(!"".is_alphanumeric());
+    assert!(!"  ".is_alphanumeric());
+    assert!("abc".is_alphanumeric());
+    assert!(!"ab c".is_alphanumeric());
+    assert!("ab2c".is_alphanumeric());
+    assert!(!"ab-c".is_alphanumeric());
+    assert!("अ१२".is_alphanumeric());
+
+    assert!("".is_alphanumeric_space());
+    assert!("  ".is_alphanumeric_space());
+    assert!("ab c".is_alphanumeric_space());
+    assert!("ab2c".is_alphanumeric_space());
+    assert!(!"ab-c".is_alphanumeric_space());
+}
+
+#[test]
+fn test_is_numeric() {
+    assert!(!"".is_numeric());
+    assert!(!"  ".is_numeric());
+    assert!("123".is_numeric());
+    assert!("१२३".is_numeric());
+    assert!("٠١٢".is_numeric());
+    assert!(!"12 3".is_numeric());
+    assert!(!"ab2c".is_numeric());
+    assert!(!"12-3".is_numeric());
+    assert!(!"12.3".is_numeric());
+    assert!(!"-123".is_numeric());
+    assert!(!"+123".is_numeric());
+    assert!(!"½".is_numeric());
+    assert!(!"²".is_numeric());
+
+    assert!("".is_numeric_space());
+    assert!("  ".is_numeric_space());
+    assert!("12 3".is_numeric_space());
+    assert!("१ २".is_numeric_space());
+    assert!(!"ab2c".is_numeric_space());
+}
+
+#[test]
+fn test_is_ascii_only() {
+    assert!("abc".is_ascii_alpha());
+    assert!(!"héllo".is_ascii_alpha());
+    assert!(!"".is_ascii_alpha());
+    assert!("ab c".is_ascii_alpha_space());
+    assert!(!"日本".is_ascii_alpha_space());
+    assert!("ab2c".is_ascii_alphanumeric());
+    assert!(!"ab२c".is_ascii_alphanumeric());
+    assert!("ab 2c".is_ascii_alphanumeric_space());
+    assert!("123".is_ascii_numeric());
+    assert!(!"१२३".is_ascii_numeric());
+    assert!(!"".is_ascii_numeric());
+    assert!("1 2".is_ascii_numeric_space());
+    assert!(!"1\u{a0}2".is_ascii_numeric_space());
+}
+
+#[test]
+fn test_is_ascii_printable() {
+    assert!("".is_ascii_printable());
+    assert!(" ".is_ascii_printable());
+    assert!("Ceki".is_ascii_printable());
+    assert!("!ab-c~".is_ascii_printable());
+    assert!(!"\u{7f}".is_ascii_printable());
+    assert!(!"\n".is_ascii_printable());
+    assert!(!"Ceki Gülcü".is_ascii_printable());
+}
+
+#[test]
+fn test_is_blank() {
+    assert!("".is_blank());
+    assert!(" ".is_blank());
+    assert!("\t\r\n\u{3000}\u{2028}\u{2029}".is_blank());
+    // Java's `Character.isWhitespace` rejects no-break spaces and NEL but accepts the
+    // information separators.
+    assert!(!"\u{a0}".is_blank());
+    assert!(!"\u{2007}".is_blank());
+    assert!(!"\u{202f}".is_blank());
+    assert!(!"\u{85}".is_blank());
+    assert!("\u{1c}\u{1d}\u{1e}\u{1f}".is_blank());
+    assert!("\u{a0}".is_not_blank());
+    assert!(!"\u{1c}".is_not_blank());
+    assert!(StringUtils::is_any_blank(["a", "\u{1f}"]));
+    assert!(!StringUtils::is_any_blank(["a", "\u{a0}"]));
+    assert!(!"bob".is_blank());
+    assert!(!"  bob  ".is_blank());
+    assert!("  bob  ".is_not_blank());
+    assert!(!" ".is_not_blank());
+
+    assert!("".is_whitespace());
+    assert!("  ".is_whitespace());
+    assert!(!"abc".is_whitespace());
+    assert!(!"ab2c".is_whitespace());
+    assert!(!"  ab-c".is_whitespace());
+    assert!("\u{2003}".is_whitespace());
+    assert!("\u{1c}\u{3000}".is_whitespace());
+    assert!(!"\u{a0}".is_whitespace());
+    assert!(!"\u{2007}".is_whitespace());
+    assert!(!"\u{202f}".is_whitespace());
+    assert!(!"\u{85}".is_whitespace());
+}
+
+#[test]
+fn test_is_empty() {
+    assert!(StringUtilsExt::is_empty(&""));
+    assert!(!StringUtilsExt::is_empty(&" "));
+    assert!(" ".is_not_empty());
+    assert!(!"".is_not_empty());
+}
+
+#[test]
+fn test_is_mixed_case() {
+    assert!(!"".is_mixed_case());
+    assert!(!" ".is_mixed_case());
+    assert!(!"A".is_mixed_case());
+    assert!(!"a".is_mixed_case());
+    assert!(!"/".is_mixed_case());
+    assert!(!"A/".is_mixed_case());
+    assert!(!"/b".is_mixed_case());
+    assert!(!"abc".is_mixed_case());
+    assert!(!"ABC".is_mixed_case());
+    assert!("aBc".is_mixed_case());
+    assert!("aBc ".is_mixed_case());
+    assert!("A c".is_mixed_case());
+    assert!("aBc/".is_mixed_case());
+    assert!("ÉtÉ été".is_mixed_case());
+    assert!(!"ǅ".is_mixed_case());
+    assert!(!"ǅž".is_mixed_case());
+    assert!("ǅŽž".is_mixed_case());
+}
+
+#[test]
+fn test_first_non_blank() {
+    assert_eq!(StringUtils::first_non_blank(Vec::<&str>::new()), None);
+    assert_eq!(StringUtils::first_non_blank(["", " ", "\n"]), None);
+    assert_eq!(StringUtils::first_non_blank(["", " ", "abc"]), Some("abc"));
+    assert_eq!(StringUtils::first_non_blank(["abc", "xyz"]), Some("abc"));
+    assert_eq!(
+        StringUtils::first_non_blank(vec![String::from(" "), String::from("xyz")]),
+        Some(String::from("xyz"))
+    );
+    assert_eq!(StringUtils::first_non_empty(["", " ", "abc"]), Some(" "));
+    assert_eq!(StringUtils::first_non_empty([""]), None);
+}
+
+#[test]
+fn test_get_common_prefix() {
+    assert_eq!(StringUtils::get_common_prefix(Vec::<&str>::new()), "");
+    assert_eq!(StringUtils::get_common_prefix(["abc"]), "abc");
+    assert_eq!(StringUtils::get_common_prefix(["", ""]), "");
+    assert_eq!(StringUtils::get_common_prefix(["", "abc"]), "");
+    assert_eq!(StringUtils::get_common_prefix(["abc", ""]), "");
+    assert_eq!(StringUtils::get_common_prefix(["abc", "abc"]), "abc");
+    assert_eq!(StringUtils::get_common_prefix(["abc", "a"]), "a");
+    assert_eq!(StringUtils::get_common_prefix(["ab", "abxyz"]), "ab");
+    assert_eq!(StringUtils::get_common_prefix(["abcde", "abxyz"]), "ab");
+    assert_eq!(StringUtils::get_common_prefix(["abcde", "xyz"]), "");
+    assert_eq!(
+        StringUtils::get_common_prefix(["i am a machine", "i am a robot"]),
+        "i am a "
+    );
+    assert_eq!(StringUtils::get_common_prefix(["héllo", "hélp"]), "hél");
+}
+
+#[test]
+fn test_index_of_difference_of_many() {
+    assert_eq!(StringUtils::index_of_difference(Vec::<&str>::new()), None);
+    assert_eq!(StringUtils::index_of_difference(["abc"]), None);
+    assert_eq!(StringUtils::index_of_difference(["", ""]), None);
+    assert_eq!(StringUtils::index_of_difference(["", "abc"]), Some(0));
+    assert_eq!(StringUtils::index_of_difference(["abc", ""]), Some(0));
+    assert_eq!(StringUtils::index_of_difference(["abc", "abc"]), None);
+    assert_eq!(StringUtils::index_of_difference(["abc", "a"]), Some(1));
+    assert_eq!(StringUtils::index_of_difference(["ab", "abxyz"]), Some(2));
+    assert_eq!(
+        StringUtils::index_of_difference(["abcde", "abxyz", "abc"]),
+        Some(2)
+    );
+    assert_eq!(StringUtils::index_of_difference(["abcde", "xyz"]), Some(0));
+}
+
+#[test]
+fn test_is_all_and_any() {
+    assert!(StringUtils::is_all_blank(Vec::<&str>::new()));
+    assert!(StringUtils::is_all_blank(["", " ", "\t"]));
+    assert!(!StringUtils::is_all_blank(["", "foo"]));
+    assert!(StringUtils::is_all_empty(["", ""]));
+    assert!(!StringUtils::is_all_empty(["", " "]));
+
+    assert!(!StringUtils::is_any_blank(Vec::<&str>::new()));
+    assert!(StringUtils::is_any_blank(["foo", " "]));
+    assert!(!StringUtils::is_any_blank(["foo", "bar"]));
+    assert!(!StringUtils::is_any_empty(Vec::<String>::new()));
+    assert!(StringUtils::is_any_empty(["foo", ""]));
+    assert!(!StringUtils::is_any_empty(["foo", " "]));
+
+    assert!(StringUtils::is_none_blank(Vec::<&str>::new()));
+    assert!(!StringUtils::is_none_blank(["foo", " "]));
+    assert!(StringUtils::is_none_blank(["foo", "bar"]));
+    assert!(!StringUtils::is_none_empty(["foo", ""]));
+    assert!(StringUtils::is_none_empty(["foo", " "]));
+}
+
+#[test]
+fn test_is_all_case() {
+    assert!(StringUtils::is_all_lower_case(["abc", "déf"]));
+    assert!(!StringUtils::is_all_lower_case(["abc", ""]));
+    assert!(!StringUtils::is_all_lower_case(["abc "]));
+    assert!(!StringUtils::is_all_lower_case(["abC"]));
+    assert!(StringUtils::is_all_upper_case(["ABC", "ÉTÉ"]));
+    assert!(!StringUtils::is_all_upper_case(["ABC", ""]));
+    assert!(!StringUtils::is_all_upper_case(["A C"]));
+    assert!(!StringUtils::is_all_upper_case(["aBC"]));
+}
+
+#[test]
+fn test_strip_all() {
+    assert_eq!(
+        StringUtils::strip_all(Vec::<&str>::new()),
+        Vec::<String>::new()
+    );
+    assert_eq!(StringUtils::strip_all(["abc", "  abc"]), ["abc", "abc"]);
+    assert_eq!(
+        StringUtils::strip_all(vec![String::from("abc  "), String::from(" abc ")]),
+        ["abc", "abc"]
+    );
+    assert_eq!(
+        StringUtils::strip_all_in(["yabcz", "zabcy"], "yz"),
+        ["abc", "abc"]
+    );
+}
+
+#[test]
+fn test_varargs_macros() {
+    let name = String::from("Bob");
+    let email = " ";
+    assert!(is_any_blank!(name, email));
+    assert!(!is_any_blank!(name, "bob@example.com"));
+    assert!(!is_any_empty!(name, email));
+    assert!(is_any_empty!(name, ""));
+    assert!(is_all_blank!(email, "", String::new()));
+    assert!(is_all_empty!("", String::new()));
+    assert!(is_none_blank!(name, "x",));
+    assert!(is_none_empty!(name, email));
+    assert_eq!(first_non_blank!("", email, name), Some("Bob"));
+    assert_eq!(first_non_empty!("", email, name), Some(" "));
+    assert_eq!(first_non_blank!("", email), None);
+}
+
+#[test]
+fn test_join() {
+    assert_eq!(StringUtils::join(Vec::<&str>::new(), ','), "");
+    assert_eq!(StringUtils::join(["a"], ','), "a");
+    assert_eq!(StringUtils::join(["a", "b", "c"], ';'), "a;b;c");
+    assert_eq!(StringUtils::join([1, 2, 3], 'é'), "1é2é3");
+    assert_eq!(StringUtils::join(["", "", "a"], ','), ",,a");
+    assert_eq!(StringUtils::join_with_str(vec!["a", "b"], "--"), "a--b");
+    assert_eq!(StringUtils::join_with_str(1..=3, ""), "123");
+    assert_eq!(
+        StringUtils::join_with_str(["a", "b"].iter().map(|s| s.to_uppercase()), ", "),
+        "A, B"
+    );
+    assert_eq!(
+        StringUtils::join_skip_none([Some("a"), None, Some("b"), None], ","),
+        "a,b"
+    );
+    assert_eq!(StringUtils::join_skip_none([None::<&str>, None], ","), "");
+}
+
+#[test]
+fn test_join_in_range() {
+    let values = ["a", "b", "c", "d"];
+    assert_eq!(
+        StringUtils::join_in_range(values, ',', 1, 3).unwrap(),
+        "b,c"
+    );
+    assert_eq!(
+        StringUtils::join_in_range(values, ',', 0, 4).unwrap(),
+        "a,b,c,d"
+    );
+    assert_eq!(StringUtils::join_in_range(values, ',', 2, 2).unwrap(), "");
+    assert_eq!(StringUtils::join_in_range(values, ',', 4, 4).unwrap(), "");
+    assert_eq!(
+        StringUtils::join_in_range_with_str(values, " | ", 0, 2).unwrap(),
+        "a | b"
+    );
+    assert!(matches!(
+        StringUtils::join_in_range(values, ',', 3, 1),
+        Err(UtilsError::InvalidArgument(_))
+    ));
+    assert!(matches!(
+        StringUtils::join_in_range(values, ',', 2, 5),
+        Err(UtilsError::InvalidArgument(_))
+    ));
+    assert!(matches!(
+        StringUtils::join_in_range_with_str(values, ",", 5, 6),
+        Err(UtilsError::InvalidArgument(_))
+    ));
+}
+
+#[test]
+fn test_string_joiner() {
+    let mut joiner = StringJoiner::new(", ").with_prefix("[").with_suffix("]");
+    assert!(joiner.is_empty());
+    assert_eq!(joiner.to_string(), "[]");
+    joiner.add("a").add(1).add('c');
+    assert!(!joiner.is_empty());
+    assert_eq!(joiner.to_string(), "[a, 1, c]");
+
+    let mut empty = StringJoiner::new(",").with_empty_value("EMPTY");
+    assert_eq!(empty.to_string(), "EMPTY");
+    empty.add("");
+    assert_eq!(empty.to_string(), "");
+
+    let mut other = StringJoiner::new("-").with_prefix("{").with_suffix("}");
+    other.extend(["x", "y"]);
+    joiner.merge(&other);
+    joiner.merge(&StringJoiner::new("+"));
+    assert_eq!(joiner.to_string(), "[a, 1, c, x-y]");
+}
+
+#[test]
+fn test_capitalize() {
+    assert_eq!("".capitalize(), "");
+    assert_eq!("cat".capitalize(), "Cat");
+    assert_eq!("cAt".capitalize(), "CAt");
+    assert_eq!("'cat'".capitalize(), "'cat'");
+    assert_eq!("ǆemal".capitalize(), "ǅemal");
+    assert_eq!("ǅemal".capitalize(), "ǅemal");
+    assert_eq!("ßa".capitalize(), "Ssa");
+    assert_eq!("ﬁsh".capitalize(), "Fish");
+    assert_eq!("ვაშლი".capitalize(), "ვაშლი");
+    assert_eq!("ᾳ".capitalize(), "ᾼ");
+    assert_eq!(String::from("élan").capitalize(), "Élan");
+}
+
+#[test]
+fn test_uncapitalize() {
+    assert_eq!("".uncapitalize(), "");
+    assert_eq!("cat".uncapitalize(), "cat");
+    assert_eq!("Cat".uncapitalize(), "cat");
+    assert_eq!("CAT".uncapitalize(), "cAT");
+    assert_eq!("ǅemal".uncapitalize(), "ǆemal");
+    assert_eq!("İx".uncapitalize(), "i\u{307}x");
+    assert_eq!("ÉLAN".uncapitalize(), "éLAN");
+}
+
+#[test]
+fn test_swap_case() {
+    assert_eq!("".swap_case(), "");
+    assert_eq!("The dog has a BONE".swap_case(), "tHE DOG HAS A bone");
+    assert_eq!("ǅ1".swap_case(), "ǆ1");
+    assert_eq!("ßA".swap_case(), "SSa");
+    assert_eq!("Σίσυφος".swap_case(), "σΊΣΥΦΟΣ");
+    assert_eq!(String::from("aB").swap_case(), "Ab");
+}
+
+#[test]
+fn test_upper_and_lower_case() {
+    assert_eq!("".upper_case(), "");
+    assert_eq!("aBc".upper_case(), "ABC");
+    assert_eq!("straße".upper_case(), "STRASSE");
+    assert_eq!("ǆ".upper_case(), "Ǆ");
+    assert_eq!("".lower_case(), "");
+    assert_eq!("aBc".lower_case(), "abc");
+    assert_eq!("İ".lower_case(), "i\u{307}");
+    assert_eq!("ΟΔΟΣ".lower_case(), "οδος");
+    assert_eq!(String::from("ABC").lower_case(), "abc");
+}
+
+#[test]
+fn test_wrap() {
+    assert_eq!("".wrap('\''), "");
+    assert_eq!("ab".wrap('\0'), "ab");
+    assert_eq!("ab".wrap('x'), "xabx");
+    assert_eq!("'ab'".wrap('\''), "''ab''");
+    assert_eq!("ab".wrap('é'), "éabé");
+    assert_eq!("".wrap_with_str("'"), "");
+    assert_eq!("ab".wrap_with_str(""), "ab");
+    assert_eq!("ab".wrap_with_str("xx"), "xxabxx");
+    assert_eq!("\"abcd\"".wrap_with_str("\""), "\"\"abcd\"\"");
+    assert_eq!(String::from("ab").wrap('"'), "\"ab\"");
+}
+
+#[test]
+fn test_wrap_if_missing() {
+    assert_eq!("".wrap_if_missing('x'), "");
+    assert_eq!("ab".wrap_if_missing('\0'), "ab");
+    assert_eq!("ab".wrap_if_missing('x'), "xabx");
+    assert_eq!("'ab'".wrap_if_missing('\''), "'ab'");
+    assert_eq!("/".wrap_if_missing('/'), "/");
+    assert_eq!("a/b/c".wrap_if_missing('/'), "/a/b/c/");
+    assert_eq!("/a/b/c".wrap_if_missing('/'), "/a/b/c/");
+    assert_eq!("".wrap_if_missing_str("x"), "");
+    assert_eq!("ab".wrap_if_missing_str(""), "ab");
+    assert_eq!("ab".wrap_if_missing_str("xx"), "xxabxx");
+    assert_eq!("xxabxx".wrap_if_missing_str("xx"), "xxabxx");
+    assert_eq!("/".wrap_if_missing_str("/"), "/");
+    assert_eq!("a/b/c/".wrap_if_missing_str("/"), "/a/b/c/");
+    assert_eq!(String::from("ab").wrap_if_missing_str("xx"), "xxabxx");
+}
+
+#[test]
+fn test_unwrap() {
+    assert_eq!("".unwrap_from_char('\''), "");
+    assert_eq!("'".unwrap_from_char('\''), "'");
+    assert_eq!("''".unwrap_from_char('\''), "");
+    assert_eq!("'abc'".unwrap_from_char('\''), "abc");
+    assert_eq!("AABabcBAA".unwrap_from_char('A'), "ABabcBA");
+    assert_eq!("A".unwrap_from_char('#'), "A");
+    assert_eq!("#A".unwrap_from_char('#'), "#A");
+    assert_eq!("A#".unwrap_from_char('#'), "A#");
+    assert_eq!("éaé".unwrap_from_char('é'), "a");
+    assert_eq!("'abc'".unwrap_from_char('\0'), "'abc'");
+    assert_eq!("".unwrap_from_str("'"), "");
+    assert_eq!("'abc'".unwrap_from_str(""), "'abc'");
+    assert_eq!("xxabxx".unwrap_from_str("xx"), "ab");
+    assert_eq!("xxx".unwrap_from_str("xx"), "xxx");
+    assert_eq!("xxxx".unwrap_from_str("xx"), "");
+    assert_eq!("AABabcBAA".unwrap_from_str("AA"), "BabcB");
+    assert_eq!("#A".unwrap_from_str("#"), "#A");
+    assert_eq!(String::from("'abc'").unwrap_from_char('\''), "abc");
+}
+
+#[test]
+fn test_unwrap_quoted() {
+    assert_eq!("'it''s'".unwrap_quoted('\''), "it's");
+    assert_eq!("'abc'".unwrap_quoted('\''), "abc");
+    assert_eq!("''".unwrap_quoted('\''), "");
+    assert_eq!("''''".unwrap_quoted('\''), "'");
+    assert_eq!("\"say \"\"hi\"\"\"".unwrap_quoted('"'), "say \"hi\"");
+    assert_eq!("'".unwrap_quoted('\''), "'");
+    assert_eq!("abc".unwrap_quoted('\''), "abc");
+    assert_eq!("'abc".unwrap_quoted('\''), "'abc");
+    assert_eq!("'a'b'".unwrap_quoted('\''), "'a'b'");
+    assert_eq!("'a' 'b'".unwrap_quoted('\''), "'a' 'b'");
+    assert_eq!("\"it's\"".unwrap_quoted('"'), "it's");
+    assert_eq!(String::from("'it''s'").unwrap_quoted('\''), "it's");
+}
+
+#[test]
+fn test_reverse() {
+    assert_eq!("".reverse(), "");
+    assert_eq!("bat".reverse(), "tab");
+    assert_eq!("noe\u{308}l".reverse(), "le\u{308}on");
+    assert_eq!("🇫🇷🇩🇪".reverse(), "🇩🇪🇫🇷");
+    assert_eq!("a👨‍👩‍👧b".reverse(), "b👨‍👩‍👧a");
+    assert_eq!("a\r\nb".reverse(), "b\r\na");
+    assert_eq!(String::from("héllo").reverse(), "olléh");
+}
+
+#[test]
+fn test_reverse_code_points() {
+    assert_eq!("".reverse_code_points(), "");
+    assert_eq!("bat".reverse_code_points(), "tab");
+    assert_eq!("noe\u{308}l".reverse_code_points(), "l\u{308}eon");
+    assert_eq!("🇫🇷".reverse_code_points(), "🇷🇫");
+    assert_eq!(String::from("héllo").reverse_code_points(), "olléh");
+}
+
+#[test]
+fn test_reverse_delimited() {
+    assert_eq!("".reverse_delimited('.'), "");
+    assert_eq!("a.b.c".reverse_delimited('x'), "a.b.c");
+    assert_eq!("a.b.c".reverse_delimited('.'), "c.b.a");
+    assert_eq!(".a..b.c.".reverse_delimited('.'), "c.b.a");
+    assert_eq!("é,ü,ö".reverse_delimited(','), "ö,ü,é");
+    assert_eq!("a.\u{301}b.c".reverse_delimited('.'), "c.a.\u{301}b");
+    assert_eq!("🇫🇷/🇩🇪".reverse_delimited('/'), "🇩🇪/🇫🇷");
+    assert_eq!(String::from("a.b.c").reverse_delimited('.'), "c.b.a");
+}
+
+#[test]
+fn test_reverse_delimited_code_points() {
+    assert_eq!("".reverse_delimited_code_points('.'), "");
+    assert_eq!("a.b.c".reverse_delimited_code_points('.'), "c.b.a");
+    assert_eq!("a..b".reverse_delimited_code_points('.'), "b.a");
+    assert_eq!(
+        "a.\u{301}b.c".reverse_delimited_code_points('.'),
+        "c.\u{301}b.a"
+    );
+    assert_eq!("x→y→z".reverse_delimited_code_points('→'), "z→y→x");
+}
+
+#[test]
+fn test_rotate() {
+    assert_eq!("".rotate(1), "");
+    assert_eq!("abcdefg".rotate(0), "abcdefg");
+    assert_eq!("abcdefg".rotate(2), "fgabcde");
+    assert_eq!("abcdefg".rotate(-2), "cdefgab");
+    assert_eq!("abcdefg".rotate(7), "abcdefg");
+    assert_eq!("abcdefg".rotate(-7), "abcdefg");
+    assert_eq!("abcdefg".rotate(9), "fgabcde");
+    assert_eq!("abcdefg".rotate(-9), "cdefgab");
+    assert_eq!("e\u{301}ab".rotate(1), "be\u{301}a");
+    assert_eq!("🇫🇷🇩🇪x".rotate(-1), "🇩🇪x🇫🇷");
+    assert_eq!(String::from("abc").rotate(isize::MIN), "cab");
+}
+
+#[test]
+fn test_rotate_code_points() {
+    assert_eq!("".rotate_code_points(1), "");
+    assert_eq!("abcdefg".rotate_code_points(2), "fgabcde");
+    assert_eq!("abcdefg".rotate_code_points(-9), "cdefgab");
+    assert_eq!("e\u{301}ab".rotate_code_points(1), "be\u{301}a");
+    assert_eq!("e\u{301}ab".rotate_code_points(-1), "\u{301}abe");
+    assert_eq!("héllo".rotate_code_points(2), "lohél");
+}
+
+#[test]
+fn test_compare() {
+    assert_eq!("".compare(""), Ordering::Equal);
+    assert_eq!("a".compare("a"), Ordering::Equal);
+    assert_eq!("a".compare("b"), Ordering::Less);
+    assert_eq!("b".compare("a"), Ordering::Greater);
+    assert_eq!("a".compare("B"), Ordering::Greater);
+    assert_eq!("ab".compare("abc"), Ordering::Less);
+    assert_eq!("é".compare("z"), Ordering::Greater);
+    assert_eq!("\u{FFFF}".compare("😀"), Ordering::Less);
+    assert_eq!(String::from("a").compare("b"), Ordering::Less);
+}
+
+#[test]
+fn test_compare_ignore_case() {
+    assert_eq!("".compare_ignore_case(""), Ordering::Equal);
+    assert_eq!("abc".compare_ignore_case("ABC"), Ordering::Equal);
+    assert_eq!("a".compare_ignore_case("B"), Ordering::Less);
+    assert_eq!("B".compare_ignore_case("a"), Ordering::Greater);
+    assert_eq!("ab".compare_ignore_case("ABC"), Ordering::Less);
+    assert_eq!("ÉCOLE".compare_ignore_case("école"), Ordering::Equal);
+    assert_eq!("ΣΑΣ".compare_ignore_case("σας"), Ordering::Equal);
+    // Cherokee folds to uppercase, and the dotless `'ı'` folds to itself.
+    assert_eq!("ꭰᏸ".compare_ignore_case("ᎠᏰ"), Ordering::Equal);
+    assert_eq!("ı".compare_ignore_case("I"), Ordering::Greater);
+    assert_eq!(
+        String::from("Abc").compare_ignore_case("abd"),
+        Ordering::Less
+    );
+}
+
+#[test]
+fn test_compare_nullable() {
+    assert_eq!(
+        StringUtils::compare_nullable(None, None, true),
+        Ordering::Equal
+    );
+    assert_eq!(
+        StringUtils::compare_nullable(None, Some("a"), true),
+        Ordering::Less
+    );
+    assert_eq!(
+        StringUtils::compare_nullable(None, Some("a"), false),
+        Ordering::Greater
+    );
+    assert_eq!(
+        StringUtils::compare_nullable(Some("a"), None, true),
+        Ordering::Greater
+    );
+    assert_eq!(
+        StringUtils::compare_nullable(Some("a"), None, false),
+        Ordering::Less
+    );
+    assert_eq!(
+        StringUtils::compare_nullable(Some("a"), Some("b"), false),
+        Ordering::Less
+    );
+    assert_eq!(
+        StringUtils::compare_ignore_case_nullable(Some("a"), Some("A"), true),
+        Ordering::Equal
+    );
+    assert_eq!(
+        StringUtils::compare_ignore_case_nullable(None, Some("A"), false),
+        Ordering::Greater
+    );
+}
+
+#[test]
+fn test_compare_nulls_in_sort_by() {
+    let mut values = vec![Some("b"), None, Some("a"), Some("C"), None];
+    values.sort_by(StringUtils::compare_nulls_first);
+    assert_eq!(values, [None, None, Some("C"), Some("a"), Some("b")]);
+    values.sort_by(StringUtils::compare_nulls_last);
+    assert_eq!(values, [Some("C"), Some("a"), Some("b"), None, None]);
+    values.sort_by(StringUtils::compare_ignore_case_nulls_first);
+    assert_eq!(values, [None, None, Some("a"), Some("b"), Some("C")]);
+    values.sort_by(StringUtils::compare_ignore_case_nulls_last);
+    assert_eq!(values, [Some("a"), Some("b"), Some("C"), None, None]);
+
+    let mut owned = vec![None, Some(String::from("b")), Some(String::from("a"))];
+    owned.sort_by(StringUtils::compare_nulls_last);
+    assert_eq!(
+        owned,
+        [Some(String::from("a")), Some(String::from("b")), None]
+    );
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn test_remove_regex() {
+    assert_eq!("any".remove_all_regex("").unwrap(), "any");
+    assert_eq!("any".remove_all_regex(".*").unwrap(), "");
+    assert_eq!("abc".remove_all_regex(".?").unwrap(), "");
+    assert_eq!("A<__>\n<__>B".remove_all_regex("<.*>").unwrap(), "A\nB");
+    assert_eq!("A<__>\n<__>B".remove_all_regex("(?s)<.*>").unwrap(), "AB");
+    assert_eq!("ABCabc123abc".remove_all_regex("[a-z]").unwrap(), "ABC123");
+    assert_eq!("héllo wörld".remove_all_regex("[éö]").unwrap(), "hllo wrld");
+    assert_eq!("any".remove_first_regex(".*").unwrap(), "");
+    assert_eq!("abc".remove_first_regex(".?").unwrap(), "bc");
+    assert_eq!(
+        "A<__>\n<__>B".remove_first_regex("<.*>").unwrap(),
+        "A\n<__>B"
+    );
+    assert_eq!(
+        "ABCabc123abc".remove_first_regex("[a-z]+").unwrap(),
+        "ABC123abc"
+    );
+    assert_eq!("A<__>\n<__>B".remove_pattern("<.*>").unwrap(), "AB");
+    assert_eq!("AB".remove_pattern("").unwrap(), "AB");
+    assert_eq!(
+        String::from("ABCabc123").remove_all_regex("[a-z]").unwrap(),
+        "ABC123"
+    );
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn test_replace_regex() {
+    assert_eq!("<__>\n<__>".replace_all_regex("<.*>", "z").unwrap(), "z\nz");
+    assert_eq!(
+        "<__>\n<__>".replace_all_regex("(?s)<.*>", "z").unwrap(),
+        "z"
+    );
+    assert_eq!(
+        "ABCabc123".replace_all_regex("[^A-Z0-9]+", "").unwrap(),
+        "ABC123"
+    );
+    assert_eq!(
+        "Lorem ipsum  dolor   sit"
+            .replace_all_regex("( +)([a-z]+)", "_$2")
+            .unwrap(),
+        "Lorem_ipsum_dolor_sit"
+    );
+    assert_eq!(
+        "ABCabc123abc"
+            .replace_first_regex("[^A-Z0-9]+", "")
+            .unwrap(),
+        "ABC123abc"
+    );
+    assert_eq!(
+        "Lorem ipsum  dolor   sit"
+            .replace_first_regex("( +)([a-z]+)", "_$2")
+            .unwrap(),
+        "Lorem_ipsum  dolor   sit"
+    );
+    assert_eq!(
+        "<A>\nxy\n</A>".replace_pattern("<A>.*</A>", "").unwrap(),
+        ""
+    );
+    assert_eq!(
+        "ABCabc123".replace_pattern("[a-z]", "_").unwrap(),
+        "ABC___123"
+    );
+    assert_eq!("a.b".replace_pattern("\\.", "$$").unwrap(), "a$b");
+    assert_eq!(
+        String::from("x1y2").replace_pattern("[0-9]", "#").unwrap(),
+        "x#y#"
+    );
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn test_invalid_regex() {
+    assert!(matches!(
+        "abc".remove_all_regex("("),
+        Err(UtilsError::InvalidRegex(_))
+    ));
+    assert!(matches!(
+        "abc".remove_first_regex("["),
+        Err(UtilsError::InvalidRegex(_))
+    ));
+    assert!(matches!(
+        "abc".remove_pattern("a)"),
+        Err(UtilsError::InvalidRegex(_))
+    ));
+    assert!(matches!(
+        "abc".replace_all_regex("(", "x"),
+        Err(UtilsError::InvalidRegex(_))
+    ));
+    assert!(matches!(
+        "abc".replace_first_regex("(", "x"),
+        Err(UtilsError::InvalidRegex(_))
+    ));
+    assert!(matches!(
+        "abc".replace_pattern("(", "x"),
+        Err(UtilsError::InvalidRegex(_))
+    ));
+}
+
+#[test]
+fn test_split_by_character_type() {
+    assert!("".split_by_character_type().is_empty());
+    assert_eq!(
+        "ab de fg".split_by_character_type(),
+        ["ab", " ", "de", " ", "fg"]
+    );
+    assert_eq!(
+        "ab   de fg".split_by_character_type(),
+        ["ab", "   ", "de", " ", "fg"]
+    );
+    assert_eq!(
+        "ab:cd:ef".split_by_character_type(),
+        ["ab", ":", "cd", ":", "ef"]
+    );
+    assert_eq!("number5".split_by_character_type(), ["number", "5"]);
+    assert_eq!("fooBar".split_by_character_type(), ["foo", "B", "ar"]);
+    assert_eq!(
+        "foo200Bar".split_by_character_type(),
+        ["foo", "200", "B", "ar"]
+    );
+    assert_eq!("ASFRules".split_by_character_type(), ["ASFR", "ules"]);
+    assert_eq!("ÉtéÀ٣٤".split_by_character_type(), ["É", "té", "À", "٣٤"]);
+    assert_eq!(
+        "日本語2024年".split_by_character_type(),
+        ["日本語", "2024", "年"]
+    );
+    let owned = String::from("fooBar");
+    assert_eq!(owned.split_by_character_type(), ["foo", "B", "ar"]);
+}
+
+#[test]
+fn test_split_by_character_type_and_camel_case() {
+    assert!("".split_by_character_type_and_camel_case().is_empty());
+    assert_eq!(
+        "ab de fg".split_by_character_type_and_camel_case(),
+        ["ab", " ", "de", " ", "fg"]
+    );
+    assert_eq!(
+        "ab:cd:ef".split_by_character_type_and_camel_case(),
+        ["ab", ":", "cd", ":", "ef"]
+    );
+    assert_eq!(
+        "number5".split_by_character_type_and_camel_case(),
+        ["number", "5"]
+    );
+    assert_eq!(
+        "fooBar".split_by_character_type_and_camel_case(),
+        ["foo", "Bar"]
+    );
+    assert_eq!(
+        "foo200Bar".split_by_character_type_and_camel_case(),
+        ["foo", "200", "Bar"]
+    );
+    assert_eq!(
+        "ASFRules".split_by_character_type_and_camel_case(),
+        ["ASF", "Rules"]
+    );
+    assert_eq!("Ab".split_by_character_type_and_camel_case(), ["Ab"]);
+    assert_eq!(
+        "ÉCOLEÉté".split_by_character_type_and_camel_case(),
+        ["ÉCOLE", "Été"]
+    );
+    let owned = String::from("parseHTTPResponse");
+    assert_eq!(
+        owned.split_by_character_type_and_camel_case(),
+        ["parse", "HTTP", "Response"]
+    );
+}
+
+#[test]
+fn test_split_by_chars() {
+    assert!("".split_by_chars(None).is_empty());
+    assert!("".split_by_chars(Some(":")).is_empty());
+    assert_eq!("abc def".split_by_chars(None), ["abc", "def"]);
+    assert_eq!("  abc  def \t\n".split_by_chars(None), ["abc", "def"]);
+    assert_eq!("a\u{3000}b".split_by_chars(None), ["a", "b"]);
+    // Whitespace follows Java's `Character.isWhitespace`.
+    assert_eq!("a\u{a0}b".split_by_chars(None), ["a\u{a0}b"]);
+    assert_eq!(
+        "a\u{202f}b\u{85}".split_by_chars(None),
+        ["a\u{202f}b\u{85}"]
+    );
+    assert_eq!("a\u{1c}b\u{1f}c".split_by_chars(None), ["a", "b", "c"]);
+    assert_eq!("abc def".split_by_chars(Some(" ")), ["abc", "def"]);
+    assert_eq!("ab:cd:ef".split_by_chars(Some(":")), ["ab", "cd", "ef"]);
+    assert_eq!("::ab::cd:".split_by_chars(Some(":")), ["ab", "cd"]);
+    assert_eq!("a.b-c".split_by_chars(Some(".-")), ["a", "b", "c"]);
+    assert_eq!("abc".split_by_chars(Some("")), ["abc"]);
+    assert_eq!(":::".split_by_chars(Some(":")), Vec::<&str>::new());
+    assert_eq!("é→ü→ö".split_by_chars(Some("→")), ["é", "ü", "ö"]);
+    let owned = String::from("a b");
+    assert_eq!(owned.split_by_chars(None), ["a", "b"]);
+}
+
+#[test]
+fn test_split_by_chars_max() {
+    assert!("".split_by_chars_max(None, 2).is_empty());
+    assert_eq!("ab de fg".split_by_chars_max(None, 0), ["ab", "de", "fg"]);
+    assert_eq!("ab   de fg".split_by_chars_max(None, 0), ["ab", "de", "fg"]);
+    assert_eq!(
+        "ab:cd:ef".split_by_chars_max(Some(":"), 0),
+        ["ab", "cd", "ef"]
+    );
+    assert_eq!("ab:cd:ef".split_by_chars_max(Some(":"), 2), ["ab", "cd:ef"]);
+    assert_eq!("ab:cd:ef".split_by_chars_max(Some(":"), 1), ["ab:cd:ef"]);
+    assert_eq!(
+        "ab::cd:ef".split_by_chars_max(Some(":"), 2),
+        ["ab", "cd:ef"]
+    );
+    assert_eq!(
+        "ab:cd:ef".split_by_chars_max(Some(":"), 5),
+        ["ab", "cd", "ef"]
+    );
+    assert_eq!("é é é".split_by_chars_max(None, 2), ["é", "é é"]);
+}
+
+#[test]
+fn test_split_by_whole_separator() {
+    assert!("".split_by_whole_separator(Some(".")).is_empty());
+    assert_eq!(
+        "ab de fg".split_by_whole_separator(None),
+        ["ab", "de", "fg"]
+    );
+    assert_eq!(
+        "ab   de fg".split_by_whole_separator(None),
+        ["ab", "de", "fg"]
+    );
+    assert_eq!(
+        "ab   de fg".split_by_whole_separator(Some("")),
+        ["ab", "de", "fg"]
+    );
+    assert_eq!(
+        "a\u{a0}b\u{1d}c".split_by_whole_separator(None),
+        ["a\u{a0}b", "c"]
+    );
+    assert_eq!(
+        "a\u{1e}b\u{2007}c".split_by_whole_separator(Some("")),
+        ["a", "b\u{2007}c"]
+    );
+    assert_eq!(
+        "ab:cd:ef".split_by_whole_separator(Some(":")),
+        ["ab", "cd", "ef"]
+    );
+    assert_eq!(
+        "ab:cd::ef".split_by_whole_separator(Some(":")),
+        ["ab", "cd", "ef"]
+    );
+    assert_eq!(
+        "ab-!-cd-!-ef".split_by_whole_separator(Some("-!-")),
+        ["ab", "cd", "ef"]
+    );
+    assert_eq!(
+        "abstemiouslyaeiouyabstemiously".split_by_whole_separator(Some("aeiouy")),
+        ["abstemiously", "abstemiously"]
+    );
+    assert_eq!("-a--b-".split_by_whole_separator(Some("-")), ["a", "b", ""]);
+    assert_eq!("a".split_by_whole_separator(Some("bc")), ["a"]);
+    assert_eq!(
+        "日本→→語".split_by_whole_separator(Some("→")),
+        ["日本", "語"]
+    );
+    let owned = String::from("a::b");
+    assert_eq!(owned.split_by_whole_separator(Some("::")), ["a", "b"]);
+}
+
+#[test]
+fn test_split_by_whole_separator_max() {
+    assert!("".split_by_whole_separator_max(Some("-"), 2).is_empty());
+    assert_eq!(
+        "ab de fg".split_by_whole_separator_max(None, 0),
+        ["ab", "de", "fg"]
+    );
+    assert_eq!(
+        "ab de fg".split_by_whole_separator_max(None, 2),
+        ["ab", "de fg"]
+    );
+    assert_eq!(
+        "ab-!-cd-!-ef".split_by_whole_separator_max(Some("-!-"), 5),
+        ["ab", "cd", "ef"]
+    );
+    assert_eq!(
+        "ab-!-cd-!-ef".split_by_whole_separator_max(Some("-!-"), 2),
+        ["ab", "cd-!-ef"]
+    );
+    assert_eq!(
+        "ab-!--!-cd-!-ef".split_by_whole_separator_max(Some("-!-"), 2),
+        ["ab", "cd-!-ef"]
+    );
+    assert_eq!(
+        "a-b-c".split_by_whole_separator_max(Some("-"), 1),
+        ["a-b-c"]
+    );
+}
+
+#[test]
+fn test_split_preserve_all_tokens() {
+    assert!("".split_preserve_all_tokens(None).is_empty());
+    assert_eq!("abc def".split_preserve_all_tokens(None), ["abc", "def"]);
+    assert_eq!(
+        "abc  def".split_preserve_all_tokens(None),
+        ["abc", "", "def"]
+    );
+    assert_eq!(" abc ".split_preserve_all_tokens(None), ["", "abc", ""]);
+    assert_eq!(
+        "\u{1f}a\u{a0}b".split_preserve_all_tokens(None),
+        ["", "a\u{a0}b"]
+    );
+    assert_eq!(
+        "ab:cd:ef".split_preserve_all_tokens(Some(":")),
+        ["ab", "cd", "ef"]
+    );
+    assert_eq!(
+        ":cd:ef".split_preserve_all_tokens(Some(":")),
+        ["", "cd", "ef"]
+    );
+    assert_eq!(
+        "ab::cd:ef".split_preserve_all_tokens(Some(":")),
+        ["ab", "", "cd", "ef"]
+    );
+    assert_eq!(
+        "ab:cd:ef:".split_preserve_all_tokens(Some(":")),
+        ["ab", "cd", "ef", ""]
+    );
+    assert_eq!(
+        "ab:cd:ef::".split_preserve_all_tokens(Some(":")),
+        ["ab", "cd", "ef", "", ""]
+    );
+    assert_eq!(":".split_preserve_all_tokens(Some(":")), ["", ""]);
+    assert_eq!("a.b-".split_preserve_all_tokens(Some(".-")), ["a", "b", ""]);
+    assert_eq!("é::ö".split_preserve_all_tokens(Some(":")), ["é", "", "ö"]);
+    let owned = String::from(":a");
+    assert_eq!(owned.split_preserve_all_tokens(Some(":")), ["", "a"]);
+}
+
+#[test]
+fn test_split_preserve_all_tokens_max() {
+    assert!("".split_preserve_all_tokens_max(None, 2).is_empty());
+    assert_eq!(
+        "ab de fg".split_preserve_all_tokens_max(None, 0),
+        ["ab", "de", "fg"]
+    );
+    assert_eq!(
+        "ab   de fg".split_preserve_all_tokens_max(None, 0),
+        ["ab", "", "", "de", "fg"]
+    );
+    assert_eq!(
+        "ab de fg".split_preserve_all_tokens_max(None, 2),
+        ["ab", "de fg"]
+    );
+    assert_eq!(
+        "ab   de fg".split_preserve_all_tokens_max(None, 2),
+        ["ab", "  de fg"]
+    );
+    assert_eq!(
+        "ab   de fg".split_preserve_all_tokens_max(None, 3),
+        ["ab", "", " de fg"]
+    );
+    assert_eq!(
+        "ab:cd:ef".split_preserve_all_tokens_max(Some(":"), 2),
+        ["ab", "cd:ef"]
+    );
+}
+
+struct Name(String);
+
+impl AsRef<str> for Name {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+fn strip_by_value<T: StringUtilsExt>(value: T) -> String {
+    String::from(value.strip())
+}
+
+#[test]
+fn test_any_as_ref_str() {
+    let owned = String::from(" héllo ");
+    assert_eq!(strip_by_value(&owned), "héllo");
+    assert_eq!(strip_by_value(" héllo "), "héllo");
+    assert_eq!(Cow::Borrowed(" héllo ").strip(), "héllo");
+    assert_eq!(Cow::<str>::Owned(owned.clone()).strip(), "héllo");
+    assert_eq!(Box::<str>::from(" héllo ").strip(), "héllo");
+    assert_eq!(std::rc::Rc::<str>::from(" héllo ").strip(), "héllo");
+    assert_eq!(std::sync::Arc::<str>::from(" héllo ").strip(), "héllo");
+    assert_eq!(Name(owned).strip(), "héllo");
+    assert_eq!(StringUtilsExt::trim("\u{3000}a "), "\u{3000}a");
+    assert!(Name(String::from("ab")).contains("b"));
+}
+
+#[test]
+fn test_borrowing_returns() {
+    let line = String::from("  GET /index.html  ");
+    let stripped: &str = line.strip();
+    assert_eq!(stripped, "GET /index.html");
+    assert_eq!(line.substring_before(' '), "");
+    assert_eq!(line.strip().substring_after(' '), "/index.html");
+    assert_eq!(line.strip().left(3), "GET");
+
+    assert!(matches!("bat".left_pad(3), Cow::Borrowed("bat")));
+    assert!(matches!("bat".left_pad(4), Cow::Owned(_)));
+    assert!(matches!(
+        "bat".right_pad_with_str(2, "xy"),
+        Cow::Borrowed("bat")
+    ));
+    assert!(matches!("bat".center(1), Cow::Borrowed("bat")));
+    assert!(matches!("Cat".capitalize(), Cow::Borrowed("Cat")));
+    assert!(matches!("cat".capitalize(), Cow::Owned(_)));
+    assert!(matches!("cat".uncapitalize(), Cow::Borrowed("cat")));
+    assert!(matches!(
+        "'ab'".wrap_if_missing('\''),
+        Cow::Borrowed("'ab'")
+    ));
+    assert!(matches!("".wrap('\''), Cow::Borrowed("")));
+    assert!(matches!("'abc'".unwrap_quoted('\''), Cow::Borrowed("abc")));
+    assert!(matches!("abc".abbreviate(5), Ok(Cow::Borrowed("abc"))));
+    assert!(matches!(
+        "abc".replace_each(&["x"], &["y"]),
+        Ok(Cow::Borrowed("abc"))
+    ));
+    assert!(matches!(
+        "abc".replace_each_repeatedly(&["x"], &["y"], None),
+        Ok(Cow::Borrowed("abc"))
+    ));
+    assert!(matches!(
+        "abc".abbreviate_middle(".", 5),
+        Cow::Borrowed("abc")
+    ));
+}
+
+#[test]
+fn test_default_if_blank_and_empty() {
+    assert_eq!("".default_if_blank("NULL"), "NULL");
+    assert_eq!(" \u{3000}".default_if_blank("NULL"), "NULL");
+    assert_eq!("bat".default_if_blank("NULL"), "bat");
+    assert_eq!("".default_if_blank(""), "");
+    assert_eq!("".default_if_empty("NULL"), "NULL");
+    assert_eq!(" ".default_if_empty("NULL"), " ");
+    assert_eq!("bat".default_if_empty("NULL"), "bat");
+    assert_eq!("bat".default_string(), "bat");
+    let owned = String::from(" ");
+    assert_eq!(owned.default_if_blank("x"), "x");
+}
+
+#[test]
+fn test_if_blank_and_empty() {
+    assert_eq!("".if_blank(|| String::from("NULL")), "NULL");
+    assert_eq!("  ".if_blank(|| String::from("NULL")), "NULL");
+    assert!(matches!(
+        "bat".if_blank(|| unreachable!()),
+        Cow::Borrowed("bat")
+    ));
+    assert_eq!("".if_empty(|| String::from("NULL")), "NULL");
+    assert_eq!(" ".if_empty(|| String::from("NULL")), " ");
+    let default = String::from("moved");
+    assert_eq!("".if_empty(move || default), "moved");
+}
+
+#[test]
+fn test_append_if_missing() {
+    assert_eq!("".append_if_missing("xyz", false), "xyz");
+    assert_eq!("abc".append_if_missing("", false), "abc");
+    assert_eq!("abc".append_if_missing("xyz", false), "abcxyz");
+    assert_eq!("abcxyz".append_if_missing("xyz", false), "abcxyz");
+    assert_eq!("abcXYZ".append_if_missing("xyz", false), "abcXYZxyz");
+    assert_eq!("abcXYZ".append_if_missing("xyz", true), "abcXYZ");
+    assert_eq!("abcÉ".append_if_missing("é", true), "abcÉ");
+    assert_eq!(
+        "abcmno".append_if_missing_one_of("xyz", &["mno"], false),
+        "abcmno"
+    );
+    assert_eq!(
+        "abcMNO".append_if_missing_one_of("xyz", &["mno"], false),
+        "abcMNOxyz"
+    );
+    assert_eq!(
+        "abcMNO".append_if_missing_one_of("xyz", &["mno"], true),
+        "abcMNO"
+    );
+    assert_eq!("abc".append_if_missing_one_of("xyz", &[""], false), "abc");
+}
+
+#[test]
+fn test_prepend_if_missing() {
+    assert_eq!("".prepend_if_missing("xyz", false), "xyz");
+    assert_eq!("abc".prepend_if_missing("", false), "abc");
+    assert_eq!("abc".prepend_if_missing("xyz", false), "xyzabc");
+    assert_eq!("xyzabc".prepend_if_missing("xyz", false), "xyzabc");
+    assert_eq!("XYZabc".prepend_if_missing("xyz", false), "xyzXYZabc");
+    assert_eq!("XYZabc".prepend_if_missing("xyz", true), "XYZabc");
+    assert_eq!(
+        "mnoabc".prepend_if_missing_one_of("xyz", &["mno"], false),
+        "mnoabc"
+    );
+    assert_eq!(
+        "MNOabc".prepend_if_missing_one_of("xyz", &["mno"], true),
+        "MNOabc"
+    );
+    assert_eq!(String::from("abc").prepend_if_missing("ü", false), "üabc");
+}
+
+#[test]
+fn test_starts_and_ends_with() {
+    assert!(StringUtilsExt::starts_with("abc", "ab", false));
+    assert!(!StringUtilsExt::starts_with("abc", "AB", false));
+    assert!(StringUtilsExt::starts_with("abc", "AB", true));
+    assert!(StringUtilsExt::starts_with("abc", "", false));
+    assert!(!StringUtilsExt::starts_with("", "a", true));
+    assert!(StringUtilsExt::starts_with("Éa", "é", true));
+    assert!("abc".end_with("bc", false));
+    assert!(!"abc".end_with("BC", false));
+    assert!("abc".end_with("BC", true));
+    assert!("abc".end_with_consider_case("c"));
+    assert!("abC".end_with_ignore_case("c"));
+    assert!("".end_with("", false));
+    assert!("abcxyz".starts_with_any(&["", "xyz"]));
+    assert!("abcxyz".starts_with_any(&["x", "abc"]));
+    assert!(!"abcxyz".starts_with_any(&["xyz", "ABC"]));
+    assert!(!"".starts_with_any(&[""]));
+    assert!("abcxyz".end_with_any(&["", "x"]));
+    assert!("abcxyz".end_with_any(&["yz", "abc"]));
+    assert!(!"abcxyz".end_with_any(&["XYZ"]));
+    assert!(!"".end_with_any(&[""]));
+}
+
+#[test]
+fn test_difference() {
+    assert_eq!("".difference(""), "");
+    assert_eq!("".difference("abc"), "abc");
+    assert_eq!("abc".difference(""), "");
+    assert_eq!("abc".difference("abc"), "");
+    assert_eq!("ab".difference("abxyz"), "xyz");
+    assert_eq!("abcde".difference("abxyz"), "xyz");
+    assert_eq!("abcde".difference("xyz"), "xyz");
+    assert_eq!("héllo".difference("hélp"), "p");
+}
+
+#[test]
+fn test_replace_chars() {
+    assert_eq!("".replace_chars('b', 'z'), "");
+    assert_eq!("abcba".replace_chars('b', 'y'), "aycya");
+    assert_eq!("abcba".replace_chars('z', 'y'), "abcba");
+    assert_eq!("héllo".replace_chars('é', 'e'), "hello");
+    assert_eq!("abc".replace_chars_in("", "x"), "abc");
+    assert_eq!("abc".replace_chars_in("b", ""), "ac");
+    assert_eq!("abcba".replace_chars_in("bc", "yz"), "ayzya");
+    assert_eq!("abcba".replace_chars_in("bc", "y"), "ayya");
+    assert_eq!("abcba".replace_chars_in("bc", "yzx"), "ayzya");
+    assert_eq!("héllö".replace_chars_in("éö", "eo"), "hello");
+    assert!(matches!(
+        "abc".replace_chars_in("xyz", "123"),
+        Cow::Borrowed("abc")
+    ));
+}
+
+#[test]
+fn test_replace_first() {
+    assert_eq!("".replace_first("a", "b"), "");
+    assert_eq!("any".replace_first("", "b"), "any");
+    assert_eq!("any".replace_first("z", "b"), "any");
+    assert_eq!("aba".replace_first("a", ""), "ba");
+    assert_eq!("aba".replace_first("a", "z"), "zba");
+    assert_eq!("héhé".replace_first("é", "e"), "hehé");
+    assert!(matches!(
+        "any".replace_first("z", "b"),
+        Cow::Borrowed("any")
+    ));
+}
+
+#[test]
+fn test_count_matches() {
+    assert_eq!("".count_matches('a'), 0);
+    assert_eq!("abba".count_matches('a'), 2);
+    assert_eq!("abba".count_matches('x'), 0);
+    assert_eq!("héhé".count_matches('é'), 2);
+    assert_eq!("abba".count_matches_str(""), 0);
+    assert_eq!("abba".count_matches_str("a"), 2);
+    assert_eq!("abba".count_matches_str("ab"), 1);
+    assert_eq!("aaaa".count_matches_str("aa"), 2);
+    assert_eq!("abba".count_matches_str("xxx"), 0);
+}
+
+#[test]
+fn test_digits() {
+    assert_eq!("".digits(), "");
+    assert_eq!("abc".digits(), "");
+    assert_eq!("1000$".digits(), "1000");
+    assert_eq!("1123~45".digits(), "112345");
+    assert_eq!("(541) 754-3010".digits(), "5417543010");
+    assert_eq!(
+        "\u{0967}\u{0968}\u{0969}".digits(),
+        "\u{0967}\u{0968}\u{0969}"
+    );
+    assert_eq!("½¹²".digits(), "");
+}
+
+#[test]
+fn test_equals() {
+    assert!("".equals(""));
+    assert!("abc".equals("abc"));
+    assert!(!"abc".equals("ABC"));
+    assert!("abc".equals_ignore_case("ABC"));
+    assert!("ΑΒΓ".equals_ignore_case("αβγ"));
+    assert!(!"abc".equals_ignore_case("abcd"));
+    assert!(!"ß".equals_ignore_case("SS"));
+    assert!("abc".equals_any(&["xyz", "abc"]));
+    assert!(!"abc".equals_any(&["ABC"]));
+    assert!(!"abc".equals_any(&[]));
+    assert!("abc".equals_any_ignore_case(&["xyz", "ABC"]));
+    assert!(!"abc".equals_any_ignore_case(&["xyz"]));
+}
+
+#[test]
+fn test_overlay() {
+    assert_eq!("".overlay("abc", 0, 0), "abc");
+    assert_eq!("abcdef".overlay("", 2, 4), "abef");
+    assert_eq!("abcdef".overlay("zzzz", 2, 4), "abzzzzef");
+    assert_eq!("abcdef".overlay("zzzz", 4, 2), "abzzzzef");
+    assert_eq!("abcdef".overlay("zzzz", 2, 8), "abzzzz");
+    assert_eq!("abcdef".overlay("zzzz", 8, 10), "abcdefzzzz");
+    assert_eq!("abcdef".overlay("zzzz", 0, 0), "zzzzabcdef");
+    assert_eq!("héllo".overlay("e", 1, 2), "hello");
+}
+
+#[test]
+fn test_repeat() {
+    assert_eq!("".repeat(3), "");
+    assert_eq!(StringUtilsExt::repeat("ab", 0), "");
+    assert_eq!(StringUtilsExt::repeat("ab", 3), "ababab");
+    assert_eq!(String::from("é").repeat(2), "éé");
+}
+
+#[test]
+fn test_option_tests() {
+    let none: Option<&str> = None;
+    assert!(none.is_blank());
+    assert!(none.is_empty());
+    assert!(!none.is_not_blank());
+    assert!(!none.is_not_empty());
+    assert!(Some(" ").is_blank());
+    assert!(!Some(" ").is_empty());
+    assert!(Some(String::from("bob")).is_not_blank());
+    assert!(!none.is_alpha());
+    assert!(!none.is_numeric());
+    assert!(!none.is_whitespace());
+    assert!(!none.contains("a"));
+    assert!(!none.contains_any(&["a"]));
+    assert!(!none.contains_only(&['a']));
+    assert!(none.contains_none(&['a']));
+    assert!(none.contains_none_in("a"));
+    assert!(!none.starts_with("", false));
+    assert!(!none.end_with("", false));
+    assert!(Some("abc").starts_with("AB", true));
+    assert!(Some("abc").contains("b"));
+    assert_eq!(none.count_matches('a'), 0);
+    assert_eq!(Some("aba").count_matches('a'), 2);
+    assert_eq!(none.index_of("a"), None);
+    assert_eq!(Some("aba").last_index_of("a"), Some(2));
+    assert_eq!(none.index_of_difference("abc"), Some(0));
+    assert_eq!(Some("abc").index_of_difference("abc"), None);
+}
+
+#[test]
+fn test_option_equals_and_compare() {
+    let none: Option<String> = None;
+    assert!(none.equals(None));
+    assert!(!none.equals(Some("")));
+    assert!(Some("abc").equals(Some("abc")));
+    assert!(!Some("abc").equals(None));
+    assert!(none.equals_ignore_case(None));
+    assert!(Some("abc").equals_ignore_case(Some("ABC")));
+    assert!(!Some("abc").equals_ignore_case(None));
+    assert!(!none.equals_any(&[""]));
+    assert_eq!(none.compare(None), Ordering::Equal);
+    assert_eq!(none.compare(Some("")), Ordering::Less);
+    assert_eq!(Some("a").compare(None), Ordering::Greater);
+    assert_eq!(Some("a").compare(Some("b")), Ordering::Less);
+    assert_eq!(Some("a").compare_ignore_case(Some("A")), Ordering::Equal);
+}
+
+#[test]
+fn test_option_defaults() {
+    let none: Option<&str> = None;
+    assert_eq!(none.default_string(), "");
+    assert_eq!(Some("bat").default_string(), "bat");
+    assert_eq!(none.default_if_blank("NULL"), "NULL");
+    assert_eq!(Some(" ").default_if_blank("NULL"), "NULL");
+    assert_eq!(Some(String::from("bat")).default_if_blank("NULL"), "bat");
+    assert_eq!(none.default_if_empty("NULL"), "NULL");
+    assert_eq!(Some(" ").default_if_empty("NULL"), " ");
+    assert_eq!(none.if_blank(|| String::from("NULL")), "NULL");
+    assert_eq!(Some("bat").if_empty(|| String::from("NULL")), "bat");
+    assert_eq!(none.difference("abc"), "abc");
+    assert_eq!(Some("ab").difference("abc"), "c");
+}
+
+#[test]
+fn test_option_strip_and_trim() {
+    let none: Option<&str> = None;
+    assert_eq!(none.strip(), None);
+    assert_eq!(Some("  ab c ").strip(), Some("ab c"));
+    assert_eq!(none.strip_to_none(), None);
+    assert_eq!(Some("").strip_to_none(), None);
+    assert_eq!(Some(" \u{3000} ").strip_to_none(), None);
+    assert_eq!(Some(" \u{1f} ").strip_to_none(), None);
+    assert_eq!(Some("  ab c ").strip_to_none(), Some("ab c"));
+    assert_eq!(none.strip_to_empty(), "");
+    assert_eq!(Some("  ab c ").strip_to_empty(), "ab c");
+    assert_eq!(none.trim(), None);
+    assert_eq!(none.trim_to_none(), None);
+    assert_eq!(Some(" \t\n ").trim_to_none(), None);
+    assert_eq!(Some(" \u{3000} ").trim_to_none(), None);
+    assert_eq!(Some(" \u{a0} ").trim_to_none(), Some("\u{a0}"));
+    assert_eq!(none.trim_to_empty(), "");
+    assert_eq!(Some(String::from("  abc  ")).trim_to_empty(), "abc");
+}
+
+#[test]
+fn test_option_transforms() {
+    let none: Option<String> = None;
+    assert!(matches!(none.abbreviate(2), Ok(None)));
+    assert_eq!(
+        Some("abcdefg").abbreviate(6).unwrap().as_deref(),
+        Some("abc...")
+    );
+    assert!(Some("abcdefg").abbreviate(3).is_err());
+    assert!(matches!(none.replace_each(&["a"], &[]), Ok(None)));
+    assert_eq!(none.capitalize(), None);
+    assert_eq!(Some("cat").capitalize().as_deref(), Some("Cat"));
+    assert_eq!(none.left_pad(3), None);
+    assert_eq!(Some("ab").left_pad(3).as_deref(), Some(" ab"));
+    assert_eq!(none.left(2), None);
+    assert_eq!(Some("abc").left(2), Some("ab"));
+    assert_eq!(none.substring_between("a", "c"), None);
+    assert_eq!(Some("abc").substring_between("a", "c"), Some("b"));
+    assert_eq!(Some("abc").substring_between("x", "c"), None);
+    assert_eq!(none.reverse(), None);
+    assert_eq!(Some("abc").reverse().as_deref(), Some("cba"));
+    assert_eq!(none.digits(), None);
+    assert_eq!(none.split_by_chars(None), None);
+    assert_eq!(Some("a b").split_by_chars(None), Some(vec!["a", "b"]));
+    assert_eq!(none.repeat(2), None);
+    assert_eq!(Some("ab").repeat(2).as_deref(), Some("abab"));
+    assert_eq!(none.upper_case(), None);
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn test_option_regex() {
+    let none: Option<&str> = None;
+    assert!(matches!(none.remove_all_regex("["), Ok(None)));
+    assert!(Some("abc").remove_all_regex("[").is_err());
+    assert_eq!(
+        Some("abc").replace_all_regex("b", "x").unwrap().as_deref(),
+        Some("axc")
+    );
+}
+
+#[test]
+fn test_delete_whitespace() {
+    assert_eq!("".delete_whitespace(), "");
+    assert_eq!("abc".delete_whitespace(), "abc");
+    assert_eq!("   ab  c  ".delete_whitespace(), "abc");
+    assert_eq!("a\tb\nc\u{3000}d\u{a0}e".delete_whitespace(), "abcde");
+    assert!(matches!("abc".delete_whitespace(), Cow::Borrowed("abc")));
+    assert_eq!("".delete_whitespace_to_none(), None);
+    assert_eq!(" \t\n".delete_whitespace_to_none(), None);
+    assert_eq!(" a b ".delete_whitespace_to_none().as_deref(), Some("ab"));
+    // `'\u{1C}'..='\u{1F}'` aren't deleted, but leave the result blank.
+    assert_eq!(" \u{1c} \u{1f}".delete_whitespace_to_none(), None);
+    assert_eq!(" \u{a0} ".delete_whitespace_to_none(), None);
+}
+
+#[test]
+fn test_normalize_space() {
+    assert_eq!("".normalize_space(), "");
+    assert_eq!("  ".normalize_space(), "");
+    assert_eq!("\t".normalize_space(), "");
+    assert_eq!("a\tb".normalize_space(), "a b");
+    assert_eq!("  a  b   c ".normalize_space(), "a b c");
+    assert_eq!("a\n\u{3000}b\u{a0}c".normalize_space(), "a b c");
+    assert!(matches!("a b c".normalize_space(), Cow::Borrowed("a b c")));
+    assert!(matches!("a\tb".normalize_space(), Cow::Owned(_)));
+    assert!(matches!("".normalize_space(), Cow::Borrowed("")));
+    assert!(matches!("abc".normalize_space(), Cow::Borrowed("abc")));
+    assert!(matches!("a\u{1f}b".normalize_space(), Cow::Borrowed(_)));
+    assert_eq!(" a".normalize_space(), "a");
+    assert_eq!("a ".normalize_space(), "a");
+    assert_eq!("a  b".normalize_space(), "a b");
+    assert_eq!("a \nb".normalize_space(), "a b");
+    assert_eq!("a\u{a0}b".normalize_space(), "a b");
+    assert_eq!(" \n ".normalize_space_to_none(), None);
+    assert_eq!(" \u{1f} ".normalize_space_to_none(), None);
+    assert_eq!("\u{1c} \u{1d}".normalize_space_to_none(), None);
+    assert_eq!(" \u{a0} ".normalize_space_to_none(), None);
+    assert_eq!(
+        " John   Smith ".normalize_space_to_none().as_deref(),
+        Some("John Smith")
+    );
+}
+
+#[test]
+fn test_strip_and_trim_to_none() {
+    assert_eq!("".strip_to_none(), None);
+    assert_eq!(" \u{3000}\n".strip_to_none(), None);
+    assert_eq!("  abc  ".strip_to_none(), Some("abc"));
+    assert_eq!(" \u{1f} ".strip_to_none(), None);
+    // A no-break space inside isn't stripped and isn't blank.
+    assert_eq!(
+        "\u{1c}\u{a0}\u{1e}".strip_to_none(),
+        Some("\u{1c}\u{a0}\u{1e}")
+    );
+    assert_eq!(" \u{a0} ".strip_to_none(), None);
+    assert_eq!("".strip_to_empty(), "");
+    assert_eq!("  abc  ".strip_to_empty(), "abc");
+    assert_eq!("".trim_to_none(), None);
+    assert_eq!(" \t\n".trim_to_none(), None);
+    assert_eq!(" \u{3000} ".trim_to_none(), None);
+    assert_eq!(" \u{2003}\u{1f} ".trim_to_none(), None);
+    assert_eq!(" \u{a0} ".trim_to_none(), Some("\u{a0}"));
+    assert_eq!("  abc  ".trim_to_none(), Some("abc"));
+    assert_eq!("  abc  ".trim_to_empty(), "abc");
+    let field = String::from("   ");
+    assert_eq!(field.strip_to_none().map(str::len), None);
+}
+
+#[test]
+fn test_substring_between_to_none() {
+    assert_eq!("<a></a>".substring_between_to_none("<a>", "</a>"), None);
+    assert_eq!("<a> </a>".substring_between_to_none("<a>", "</a>"), None);
+    assert_eq!(
+        "<a>x</a>".substring_between_to_none("<a>", "</a>"),
+        Some("x")
+    );
+    assert_eq!("<a>x".substring_between_to_none("<a>", "</a>"), None);
+    assert_eq!(Some("[ ]").substring_between_to_none("[", "]"), None);
+    assert_eq!(
+        Some(" [ 42 ] ").normalize_space_to_none().as_deref(),
+        Some("[ 42 ]")
+    );
+    assert_eq!(Some(" \u{1f} ").normalize_space_to_none(), None);
+    assert_eq!(Some("\u{1c}\u{1d}").delete_whitespace_to_none(), None);
+    let none: Option<&str> = None;
+    assert_eq!(none.delete_whitespace_to_none(), None);
+}
+
+#[test]
+fn test_display_width() {
+    assert_eq!("".display_width(), 0);
+    assert_eq!("abc".display_width(), 3);
+    assert_eq!("中文".display_width(), 4);
+    assert_eq!("Ａｂ".display_width(), 4);
+    assert_eq!("ｱｲ".display_width(), 2);
+    assert_eq!("e\u{301}".display_width(), 1);
+    assert_eq!("a\u{200b}b".display_width(), 2);
+    assert_eq!("👍🏽".display_width(), 2);
+    assert_eq!("👨\u{200d}👩\u{200d}👧".display_width(), 2);
+    assert_eq!("🇯🇵".display_width(), 2);
+    assert_eq!(String::from("表格").display_width(), 4);
+}
+
+#[test]
+fn test_truncate_to_width() {
+    assert_eq!("".truncate_to_width(3), "");
+    assert_eq!("abcdef".truncate_to_width(3), "abc");
+    assert_eq!("abc".truncate_to_width(10), "abc");
+    assert_eq!("中文字符".truncate_to_width(4), "中文");
+    assert_eq!("中文字符".truncate_to_width(5), "中文");
+    assert_eq!("a中".truncate_to_width(2), "a");
+    assert_eq!("中".truncate_to_width(1), "");
+    assert_eq!("e\u{301}x".truncate_to_width(1), "e\u{301}");
+    assert_eq!("👍👍".truncate_to_width(3), "👍");
+    assert_eq!(
+        "👨\u{200d}👩\u{200d}👧x".truncate_to_width(2),
+        "👨\u{200d}👩\u{200d}👧"
+    );
+    assert_eq!("ab中文".truncate_to_width_with_offset(1, 3), "b中");
+    assert_eq!("ab中文".truncate_to_width_with_offset(2, 3), "中");
+    assert_eq!("ab中文".truncate_to_width_with_offset(9, 3), "");
+}
+
+#[test]
+fn test_abbreviate_to_width() {
+    assert!(matches!("".abbreviate_to_width(4), Ok(Cow::Borrowed(""))));
+    assert!(matches!(
+        "中文".abbreviate_to_width(4),
+        Ok(Cow::Borrowed("中文"))
+    ));
+    assert_eq!("abcdefghij".abbreviate_to_width(6).unwrap(), "abc...");
+    assert_eq!(
+        "中华人民共和国万岁".abbreviate_to_width(10).unwrap(),
+        "中华人..."
+    );
+    assert_eq!("中文字".abbreviate_to_width(4).unwrap(), "...");
+    assert_eq!("中文字".abbreviate_to_width(5).unwrap(), "中...");
+    assert_eq!("👍👍👍".abbreviate_to_width(5).unwrap(), "👍...");
+    assert!(matches!(
+        "中文字".abbreviate_to_width(3),
+        Err(UtilsError::InvalidArgument(_))
+    ));
+    assert_eq!(
+        "中文字符"
+            .abbreviate_to_width_with_abbrev_marker("…", 5)
+            .unwrap(),
+        "中文…"
+    );
+    assert_eq!(
+        "中文字符"
+            .abbreviate_to_width_with_abbrev_marker("", 5)
+            .unwrap(),
+        "中文"
+    );
+    assert!("中文字符"
+        .abbreviate_to_width_with_abbrev_marker("……", 2)
+        .is_err());
+}
+
+#[test]
+fn test_abbreviate_to_width_with_full_opt() {
+    let numbers = "一二三四五六七八九十";
+    assert_eq!(
+        numbers
+            .abbreviate_to_width_with_full_opt("...", 5, 12)
+            .unwrap(),
+        "...六七八..."
+    );
+    assert_eq!(
+        numbers
+            .abbreviate_to_width_with_full_opt("...", 8, 12)
+            .unwrap(),
+        "...七八九十"
+    );
+    assert_eq!(
+        numbers
+            .abbreviate_to_width_with_full_opt("...", 1, 12)
+            .unwrap(),
+        "一二三四..."
+    );
+    assert!(numbers
+        .abbreviate_to_width_with_full_opt("...", 5, 6)
+        .is_err());
+    for offset in 0..10 {
+        for max_width in 4..20 {
+            let abbreviated = numbers
+                .abbreviate_to_width_with_full_opt("…", offset, max_width)
+                .unwrap();
+            assert!(abbreviated.display_width() <= max_width);
+            assert!(abbreviated.display_width() + 1 >= max_width);
+        }
+    }
+
+    // An offset inside a grapheme cluster counts the whole cluster, even where its first char
+    // alone would be wider than the cluster.
+    assert!("⌚\u{FE0E}"
+        .repeat(6)
+        .abbreviate_to_width_with_full_opt("...", 11, 4)
+        .is_err());
+    for text in [
+        "⌚\u{FE0E}".repeat(20),
+        "👩\u{200D}👩\u{200D}👧x".repeat(10),
+    ] {
+        for offset in 0..=45 {
+            for max_width in 4..=11 {
+                if let Ok(abbreviated) =
+                    text.abbreviate_to_width_with_full_opt("...", offset, max_width)
+                {
+                    assert!(abbreviated.display_width() <= max_width);
+                }
+            }
+        }
+    }
+    assert_eq!(
+        "⌚\u{FE0E}"
+            .repeat(20)
+            .abbreviate_to_width_with_full_opt("...", 39, 11)
+            .unwrap(),
+        format!("...{}", "⌚\u{FE0E}".repeat(8))
+    );
+
+    // On ASCII text columns and chars agree, so the result matches the char-based version.
+    let alphabet = "abcdefghijklmno";
+    for offset in -1..=16 {
+        for max_width in 0..=16 {
+            let by_chars = alphabet.abbreviate_with_full_opt("...", offset, max_width);
+            let by_width = alphabet.abbreviate_to_width_with_full_opt("...", offset, max_width);
+            match (by_chars, by_width) {
+                (Ok(by_chars), Ok(by_width)) => assert_eq!(by_chars, by_width),
+                (Err(_), Err(_)) => {}
+                (by_chars, by_width) => panic!(
+                    "offset {} max_width {}: {:?} vs {:?}",
+                    offset, max_width, by_chars, by_width
+                ),
+            }
+        }
+    }
+
+    let none: Option<&str> = None;
+    assert!(matches!(none.abbreviate_to_width(1), Ok(None)));
+    assert_eq!(none.display_width(), 0);
+    assert_eq!(Some("中文字").truncate_to_width(5), Some("中文"));
+}
+
+#[test]
+fn test_pad_to_width() {
+    assert!(matches!("中文".left_pad_to_width(3), Cow::Borrowed("中文")));
+    assert!(matches!(
+        "中文".right_pad_to_width(4),
+        Cow::Borrowed("中文")
+    ));
+    assert_eq!("".left_pad_to_width(2), "  ");
+    assert_eq!("中".left_pad_to_width(4), "  中");
+    assert_eq!("中".right_pad_to_width(4), "中  ");
+    assert_eq!("e\u{301}".right_pad_to_width(3), "e\u{301}  ");
+    for cell in ["a", "中文", "👍", "e\u{301}", "ｱｲｳ", ""] {
+        assert_eq!(cell.right_pad_to_width(6).display_width(), 6);
+        assert_eq!(cell.left_pad_to_width(6).display_width(), 6);
+        assert_eq!(cell.center_to_width(6).display_width(), 6);
+    }
+
+    assert_eq!(
+        "ab".left_pad_to_width_with_str(5, "中", PadPolicy::FillWithSpace),
+        "中 ab"
+    );
+    assert_eq!(
+        "ab".left_pad_to_width_with_str(5, "中", PadPolicy::Overflow),
+        "中中ab"
+    );
+    assert_eq!(
+        "ab".left_pad_to_width_with(6, '中', PadPolicy::FillWithSpace),
+        "中中ab"
+    );
+    assert_eq!(
+        "ab".left_pad_to_width_with_str(6, "xy", PadPolicy::Overflow),
+        "xyxyab"
+    );
+    assert_eq!(
+        "ab".right_pad_to_width_with_str(5, "中x", PadPolicy::FillWithSpace),
+        "ab中x"
+    );
+    assert_eq!(
+        "ab".right_pad_to_width_with_str(7, "x中", PadPolicy::FillWithSpace),
+        "abx中x "
+    );
+    assert_eq!(
+        "ab".right_pad_to_width_with_str(7, "x中", PadPolicy::Overflow),
+        "abx中x中"
+    );
+    assert_eq!(
+        "ab".right_pad_to_width_with(3, '👍', PadPolicy::FillWithSpace),
+        "ab "
+    );
+    assert_eq!(
+        "a".left_pad_to_width_with_str(3, "", PadPolicy::Overflow),
+        "  a"
+    );
+    assert_eq!(
+        "a".left_pad_to_width_with_str(3, "\u{200b}", PadPolicy::Overflow),
+        "  a"
+    );
+    assert_eq!(PadPolicy::default(), PadPolicy::FillWithSpace);
+}
+
+#[test]
+fn test_center_to_width() {
+    assert!(matches!("中文".center_to_width(4), Cow::Borrowed("中文")));
+    assert_eq!("中".center_to_width(5), " 中  ");
+    assert_eq!("中".center_to_width(6), "  中  ");
+    assert_eq!(
+        "中".center_to_width_with_char(7, '・', PadPolicy::FillWithSpace),
+        "・中・ "
+    );
+    assert_eq!(
+        "中".center_to_width_with_char(7, '・', PadPolicy::Overflow),
+        "・中・・"
+    );
+    assert_eq!(
+        "ab".center_to_width_with(6, "-=", PadPolicy::FillWithSpace),
+        "-=ab-="
+    );
+
+    let none: Option<&str> = None;
+    assert_eq!(none.center_to_width(4), None);
+    assert_eq!(Some("中").left_pad_to_width(3).as_deref(), Some(" 中"));
 }